@@ -12,9 +12,23 @@ export interface AuctionFields {
   startOrderPhase: BN
   endOrderPhase: BN
   endDecryptionPhase: BN
+  softCloseWindow: BN
+  softCloseExtension: BN
+  maxSoftCloseExtension: BN
+  totalSoftCloseExtension: BN
   areAsksEncrypted: boolean
   areBidsEncrypted: boolean
   naclPubkey: Array<number>
+  isCommitReveal: boolean
+  unrevealedPenaltyBps: number
+  committee: Array<PublicKey>
+  committeeThreshold: number
+  committeePublicShares: Array<Array<number>>
+  keyShares: Array<types.KeyShareFields>
+  committeeSecretKey: Array<number>
+  finalPriceType: types.FinalPriceTypesKind
+  fillAllocationType: types.FillAllocationTypesKind
+  isPayAsBid: boolean
   eventQueue: PublicKey
   bids: PublicKey
   asks: PublicKey
@@ -22,8 +36,22 @@ export interface AuctionFields {
   baseMint: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
+  baseLotSize: BN
+  quoteLotSize: BN
   minBaseOrderSize: BN
   tickSize: BN
+  maxBasePerUser: BN
+  allowlistRoot: Array<number>
+  reservePrice: BN
+  reservePriceCommitment: Array<number>
+  minBaseMatched: BN
+  minQuoteRaised: BN
+  fixedBaseSupply: BN
+  hasDepositedFixedSupply: boolean
+  vestingCliff: BN
+  vestingDuration: BN
   askSearchStackDepth: number
   bidSearchStackDepth: number
   askSearchStackValues: Array<number>
@@ -33,13 +61,30 @@ export interface AuctionFields {
   currentBidQuantityFilled: BN
   currentAskQuantityFilled: BN
   totalQuantityFilledSoFar: BN
+  currentBidLevelQuantity: BN
+  currentAskLevelQuantity: BN
+  filledBidLevelsQuantity: BN
+  filledAskLevelsQuantity: BN
+  hasCrossed: boolean
+  isBidLevelComplete: boolean
+  isAskLevelComplete: boolean
+  firstLosingBidPrice: BN | null
   hasFoundClearingPrice: boolean
+  hasFailed: boolean
   totalQuantityMatched: BN
   remainingAskFills: BN
   remainingBidFills: BN
   finalBidPrice: BN
   finalAskPrice: BN
   clearingPrice: BN
+  marginalBidQuantity: BN
+  marginalAskQuantity: BN
+  baseSold: BN
+  quoteProceeds: BN
+  hasWithdrawnProceeds: boolean
+  isCancelled: boolean
+  numOpenOrders: number
+  baseVestingUnclaimed: BN
 }
 
 export interface AuctionJSON {
@@ -50,9 +95,23 @@ export interface AuctionJSON {
   startOrderPhase: string
   endOrderPhase: string
   endDecryptionPhase: string
+  softCloseWindow: string
+  softCloseExtension: string
+  maxSoftCloseExtension: string
+  totalSoftCloseExtension: string
   areAsksEncrypted: boolean
   areBidsEncrypted: boolean
   naclPubkey: Array<number>
+  isCommitReveal: boolean
+  unrevealedPenaltyBps: number
+  committee: Array<string>
+  committeeThreshold: number
+  committeePublicShares: Array<Array<number>>
+  keyShares: Array<types.KeyShareJSON>
+  committeeSecretKey: Array<number>
+  finalPriceType: types.FinalPriceTypesJSON
+  fillAllocationType: types.FillAllocationTypesJSON
+  isPayAsBid: boolean
   eventQueue: string
   bids: string
   asks: string
@@ -60,8 +119,22 @@ export interface AuctionJSON {
   baseMint: string
   quoteVault: string
   baseVault: string
+  quoteTokenProgram: string
+  baseTokenProgram: string
+  baseLotSize: string
+  quoteLotSize: string
   minBaseOrderSize: string
   tickSize: string
+  maxBasePerUser: string
+  allowlistRoot: Array<number>
+  reservePrice: string
+  reservePriceCommitment: Array<number>
+  minBaseMatched: string
+  minQuoteRaised: string
+  fixedBaseSupply: string
+  hasDepositedFixedSupply: boolean
+  vestingCliff: string
+  vestingDuration: string
   askSearchStackDepth: number
   bidSearchStackDepth: number
   askSearchStackValues: Array<number>
@@ -71,13 +144,30 @@ export interface AuctionJSON {
   currentBidQuantityFilled: string
   currentAskQuantityFilled: string
   totalQuantityFilledSoFar: string
+  currentBidLevelQuantity: string
+  currentAskLevelQuantity: string
+  filledBidLevelsQuantity: string
+  filledAskLevelsQuantity: string
+  hasCrossed: boolean
+  isBidLevelComplete: boolean
+  isAskLevelComplete: boolean
+  firstLosingBidPrice: string | null
   hasFoundClearingPrice: boolean
+  hasFailed: boolean
   totalQuantityMatched: string
   remainingAskFills: string
   remainingBidFills: string
   finalBidPrice: string
   finalAskPrice: string
   clearingPrice: string
+  marginalBidQuantity: string
+  marginalAskQuantity: string
+  baseSold: string
+  quoteProceeds: string
+  hasWithdrawnProceeds: boolean
+  isCancelled: boolean
+  numOpenOrders: number
+  baseVestingUnclaimed: string
 }
 
 export class Auction {
@@ -88,9 +178,23 @@ export class Auction {
   readonly startOrderPhase: BN
   readonly endOrderPhase: BN
   readonly endDecryptionPhase: BN
+  readonly softCloseWindow: BN
+  readonly softCloseExtension: BN
+  readonly maxSoftCloseExtension: BN
+  readonly totalSoftCloseExtension: BN
   readonly areAsksEncrypted: boolean
   readonly areBidsEncrypted: boolean
   readonly naclPubkey: Array<number>
+  readonly isCommitReveal: boolean
+  readonly unrevealedPenaltyBps: number
+  readonly committee: Array<PublicKey>
+  readonly committeeThreshold: number
+  readonly committeePublicShares: Array<Array<number>>
+  readonly keyShares: Array<types.KeyShare>
+  readonly committeeSecretKey: Array<number>
+  readonly finalPriceType: types.FinalPriceTypesKind
+  readonly fillAllocationType: types.FillAllocationTypesKind
+  readonly isPayAsBid: boolean
   readonly eventQueue: PublicKey
   readonly bids: PublicKey
   readonly asks: PublicKey
@@ -98,8 +202,22 @@ export class Auction {
   readonly baseMint: PublicKey
   readonly quoteVault: PublicKey
   readonly baseVault: PublicKey
+  readonly quoteTokenProgram: PublicKey
+  readonly baseTokenProgram: PublicKey
+  readonly baseLotSize: BN
+  readonly quoteLotSize: BN
   readonly minBaseOrderSize: BN
   readonly tickSize: BN
+  readonly maxBasePerUser: BN
+  readonly allowlistRoot: Array<number>
+  readonly reservePrice: BN
+  readonly reservePriceCommitment: Array<number>
+  readonly minBaseMatched: BN
+  readonly minQuoteRaised: BN
+  readonly fixedBaseSupply: BN
+  readonly hasDepositedFixedSupply: boolean
+  readonly vestingCliff: BN
+  readonly vestingDuration: BN
   readonly askSearchStackDepth: number
   readonly bidSearchStackDepth: number
   readonly askSearchStackValues: Array<number>
@@ -109,13 +227,30 @@ export class Auction {
   readonly currentBidQuantityFilled: BN
   readonly currentAskQuantityFilled: BN
   readonly totalQuantityFilledSoFar: BN
+  readonly currentBidLevelQuantity: BN
+  readonly currentAskLevelQuantity: BN
+  readonly filledBidLevelsQuantity: BN
+  readonly filledAskLevelsQuantity: BN
+  readonly hasCrossed: boolean
+  readonly isBidLevelComplete: boolean
+  readonly isAskLevelComplete: boolean
+  readonly firstLosingBidPrice: BN | null
   readonly hasFoundClearingPrice: boolean
+  readonly hasFailed: boolean
   readonly totalQuantityMatched: BN
   readonly remainingAskFills: BN
   readonly remainingBidFills: BN
   readonly finalBidPrice: BN
   readonly finalAskPrice: BN
   readonly clearingPrice: BN
+  readonly marginalBidQuantity: BN
+  readonly marginalAskQuantity: BN
+  readonly baseSold: BN
+  readonly quoteProceeds: BN
+  readonly hasWithdrawnProceeds: boolean
+  readonly isCancelled: boolean
+  readonly numOpenOrders: number
+  readonly baseVestingUnclaimed: BN
 
   static readonly discriminator = Buffer.from([
     218, 94, 247, 242, 126, 233, 131, 81,
@@ -129,9 +264,23 @@ export class Auction {
     borsh.i64("startOrderPhase"),
     borsh.i64("endOrderPhase"),
     borsh.i64("endDecryptionPhase"),
+    borsh.i64("softCloseWindow"),
+    borsh.i64("softCloseExtension"),
+    borsh.i64("maxSoftCloseExtension"),
+    borsh.i64("totalSoftCloseExtension"),
     borsh.bool("areAsksEncrypted"),
     borsh.bool("areBidsEncrypted"),
     borsh.vecU8("naclPubkey"),
+    borsh.bool("isCommitReveal"),
+    borsh.u16("unrevealedPenaltyBps"),
+    borsh.vec(borsh.publicKey(), "committee"),
+    borsh.u8("committeeThreshold"),
+    borsh.vec(borsh.array(borsh.u8(), 32), "committeePublicShares"),
+    borsh.vec(types.KeyShare.layout(), "keyShares"),
+    borsh.array(borsh.u8(), 32, "committeeSecretKey"),
+    types.FinalPriceTypes.layout("finalPriceType"),
+    types.FillAllocationTypes.layout("fillAllocationType"),
+    borsh.bool("isPayAsBid"),
     borsh.publicKey("eventQueue"),
     borsh.publicKey("bids"),
     borsh.publicKey("asks"),
@@ -139,8 +288,22 @@ export class Auction {
     borsh.publicKey("baseMint"),
     borsh.publicKey("quoteVault"),
     borsh.publicKey("baseVault"),
+    borsh.publicKey("quoteTokenProgram"),
+    borsh.publicKey("baseTokenProgram"),
+    borsh.u64("baseLotSize"),
+    borsh.u64("quoteLotSize"),
     borsh.u64("minBaseOrderSize"),
     borsh.u64("tickSize"),
+    borsh.u64("maxBasePerUser"),
+    borsh.array(borsh.u8(), 32, "allowlistRoot"),
+    borsh.u64("reservePrice"),
+    borsh.array(borsh.u8(), 32, "reservePriceCommitment"),
+    borsh.u64("minBaseMatched"),
+    borsh.u64("minQuoteRaised"),
+    borsh.u64("fixedBaseSupply"),
+    borsh.bool("hasDepositedFixedSupply"),
+    borsh.i64("vestingCliff"),
+    borsh.i64("vestingDuration"),
     borsh.u8("askSearchStackDepth"),
     borsh.u8("bidSearchStackDepth"),
     borsh.array(borsh.u32(), 32, "askSearchStackValues"),
@@ -150,13 +313,30 @@ export class Auction {
     borsh.u64("currentBidQuantityFilled"),
     borsh.u64("currentAskQuantityFilled"),
     borsh.u64("totalQuantityFilledSoFar"),
+    borsh.u64("currentBidLevelQuantity"),
+    borsh.u64("currentAskLevelQuantity"),
+    borsh.u64("filledBidLevelsQuantity"),
+    borsh.u64("filledAskLevelsQuantity"),
+    borsh.bool("hasCrossed"),
+    borsh.bool("isBidLevelComplete"),
+    borsh.bool("isAskLevelComplete"),
+    borsh.option(borsh.u64(), "firstLosingBidPrice"),
     borsh.bool("hasFoundClearingPrice"),
+    borsh.bool("hasFailed"),
     borsh.u64("totalQuantityMatched"),
     borsh.u64("remainingAskFills"),
     borsh.u64("remainingBidFills"),
     borsh.u64("finalBidPrice"),
     borsh.u64("finalAskPrice"),
     borsh.u64("clearingPrice"),
+    borsh.u64("marginalBidQuantity"),
+    borsh.u64("marginalAskQuantity"),
+    borsh.u64("baseSold"),
+    borsh.u64("quoteProceeds"),
+    borsh.bool("hasWithdrawnProceeds"),
+    borsh.bool("isCancelled"),
+    borsh.u32("numOpenOrders"),
+    borsh.u64("baseVestingUnclaimed"),
   ])
 
   constructor(fields: AuctionFields) {
@@ -167,9 +347,25 @@ export class Auction {
     this.startOrderPhase = fields.startOrderPhase
     this.endOrderPhase = fields.endOrderPhase
     this.endDecryptionPhase = fields.endDecryptionPhase
+    this.softCloseWindow = fields.softCloseWindow
+    this.softCloseExtension = fields.softCloseExtension
+    this.maxSoftCloseExtension = fields.maxSoftCloseExtension
+    this.totalSoftCloseExtension = fields.totalSoftCloseExtension
     this.areAsksEncrypted = fields.areAsksEncrypted
     this.areBidsEncrypted = fields.areBidsEncrypted
     this.naclPubkey = fields.naclPubkey
+    this.isCommitReveal = fields.isCommitReveal
+    this.unrevealedPenaltyBps = fields.unrevealedPenaltyBps
+    this.committee = fields.committee
+    this.committeeThreshold = fields.committeeThreshold
+    this.committeePublicShares = fields.committeePublicShares
+    this.keyShares = fields.keyShares.map(
+      (item) => new types.KeyShare({ ...item })
+    )
+    this.committeeSecretKey = fields.committeeSecretKey
+    this.finalPriceType = fields.finalPriceType
+    this.fillAllocationType = fields.fillAllocationType
+    this.isPayAsBid = fields.isPayAsBid
     this.eventQueue = fields.eventQueue
     this.bids = fields.bids
     this.asks = fields.asks
@@ -177,8 +373,22 @@ export class Auction {
     this.baseMint = fields.baseMint
     this.quoteVault = fields.quoteVault
     this.baseVault = fields.baseVault
+    this.quoteTokenProgram = fields.quoteTokenProgram
+    this.baseTokenProgram = fields.baseTokenProgram
+    this.baseLotSize = fields.baseLotSize
+    this.quoteLotSize = fields.quoteLotSize
     this.minBaseOrderSize = fields.minBaseOrderSize
     this.tickSize = fields.tickSize
+    this.maxBasePerUser = fields.maxBasePerUser
+    this.allowlistRoot = fields.allowlistRoot
+    this.reservePrice = fields.reservePrice
+    this.reservePriceCommitment = fields.reservePriceCommitment
+    this.minBaseMatched = fields.minBaseMatched
+    this.minQuoteRaised = fields.minQuoteRaised
+    this.fixedBaseSupply = fields.fixedBaseSupply
+    this.hasDepositedFixedSupply = fields.hasDepositedFixedSupply
+    this.vestingCliff = fields.vestingCliff
+    this.vestingDuration = fields.vestingDuration
     this.askSearchStackDepth = fields.askSearchStackDepth
     this.bidSearchStackDepth = fields.bidSearchStackDepth
     this.askSearchStackValues = fields.askSearchStackValues
@@ -188,13 +398,30 @@ export class Auction {
     this.currentBidQuantityFilled = fields.currentBidQuantityFilled
     this.currentAskQuantityFilled = fields.currentAskQuantityFilled
     this.totalQuantityFilledSoFar = fields.totalQuantityFilledSoFar
+    this.currentBidLevelQuantity = fields.currentBidLevelQuantity
+    this.currentAskLevelQuantity = fields.currentAskLevelQuantity
+    this.filledBidLevelsQuantity = fields.filledBidLevelsQuantity
+    this.filledAskLevelsQuantity = fields.filledAskLevelsQuantity
+    this.hasCrossed = fields.hasCrossed
+    this.isBidLevelComplete = fields.isBidLevelComplete
+    this.isAskLevelComplete = fields.isAskLevelComplete
+    this.firstLosingBidPrice = fields.firstLosingBidPrice
     this.hasFoundClearingPrice = fields.hasFoundClearingPrice
+    this.hasFailed = fields.hasFailed
     this.totalQuantityMatched = fields.totalQuantityMatched
     this.remainingAskFills = fields.remainingAskFills
     this.remainingBidFills = fields.remainingBidFills
     this.finalBidPrice = fields.finalBidPrice
     this.finalAskPrice = fields.finalAskPrice
     this.clearingPrice = fields.clearingPrice
+    this.marginalBidQuantity = fields.marginalBidQuantity
+    this.marginalAskQuantity = fields.marginalAskQuantity
+    this.baseSold = fields.baseSold
+    this.quoteProceeds = fields.quoteProceeds
+    this.hasWithdrawnProceeds = fields.hasWithdrawnProceeds
+    this.isCancelled = fields.isCancelled
+    this.numOpenOrders = fields.numOpenOrders
+    this.baseVestingUnclaimed = fields.baseVestingUnclaimed
   }
 
   static async fetch(
@@ -246,9 +473,25 @@ export class Auction {
       startOrderPhase: dec.startOrderPhase,
       endOrderPhase: dec.endOrderPhase,
       endDecryptionPhase: dec.endDecryptionPhase,
+      softCloseWindow: dec.softCloseWindow,
+      softCloseExtension: dec.softCloseExtension,
+      maxSoftCloseExtension: dec.maxSoftCloseExtension,
+      totalSoftCloseExtension: dec.totalSoftCloseExtension,
       areAsksEncrypted: dec.areAsksEncrypted,
       areBidsEncrypted: dec.areBidsEncrypted,
       naclPubkey: Array.from(dec.naclPubkey),
+      isCommitReveal: dec.isCommitReveal,
+      unrevealedPenaltyBps: dec.unrevealedPenaltyBps,
+      committee: dec.committee,
+      committeeThreshold: dec.committeeThreshold,
+      committeePublicShares: dec.committeePublicShares,
+      keyShares: dec.keyShares.map((item) => types.KeyShare.fromDecoded(item)),
+      committeeSecretKey: dec.committeeSecretKey,
+      finalPriceType: types.FinalPriceTypes.fromDecoded(dec.finalPriceType),
+      fillAllocationType: types.FillAllocationTypes.fromDecoded(
+        dec.fillAllocationType
+      ),
+      isPayAsBid: dec.isPayAsBid,
       eventQueue: dec.eventQueue,
       bids: dec.bids,
       asks: dec.asks,
@@ -256,8 +499,22 @@ export class Auction {
       baseMint: dec.baseMint,
       quoteVault: dec.quoteVault,
       baseVault: dec.baseVault,
+      quoteTokenProgram: dec.quoteTokenProgram,
+      baseTokenProgram: dec.baseTokenProgram,
+      baseLotSize: dec.baseLotSize,
+      quoteLotSize: dec.quoteLotSize,
       minBaseOrderSize: dec.minBaseOrderSize,
       tickSize: dec.tickSize,
+      maxBasePerUser: dec.maxBasePerUser,
+      allowlistRoot: dec.allowlistRoot,
+      reservePrice: dec.reservePrice,
+      reservePriceCommitment: dec.reservePriceCommitment,
+      minBaseMatched: dec.minBaseMatched,
+      minQuoteRaised: dec.minQuoteRaised,
+      fixedBaseSupply: dec.fixedBaseSupply,
+      hasDepositedFixedSupply: dec.hasDepositedFixedSupply,
+      vestingCliff: dec.vestingCliff,
+      vestingDuration: dec.vestingDuration,
       askSearchStackDepth: dec.askSearchStackDepth,
       bidSearchStackDepth: dec.bidSearchStackDepth,
       askSearchStackValues: dec.askSearchStackValues,
@@ -267,13 +524,30 @@ export class Auction {
       currentBidQuantityFilled: dec.currentBidQuantityFilled,
       currentAskQuantityFilled: dec.currentAskQuantityFilled,
       totalQuantityFilledSoFar: dec.totalQuantityFilledSoFar,
+      currentBidLevelQuantity: dec.currentBidLevelQuantity,
+      currentAskLevelQuantity: dec.currentAskLevelQuantity,
+      filledBidLevelsQuantity: dec.filledBidLevelsQuantity,
+      filledAskLevelsQuantity: dec.filledAskLevelsQuantity,
+      hasCrossed: dec.hasCrossed,
+      isBidLevelComplete: dec.isBidLevelComplete,
+      isAskLevelComplete: dec.isAskLevelComplete,
+      firstLosingBidPrice: dec.firstLosingBidPrice,
       hasFoundClearingPrice: dec.hasFoundClearingPrice,
+      hasFailed: dec.hasFailed,
       totalQuantityMatched: dec.totalQuantityMatched,
       remainingAskFills: dec.remainingAskFills,
       remainingBidFills: dec.remainingBidFills,
       finalBidPrice: dec.finalBidPrice,
       finalAskPrice: dec.finalAskPrice,
       clearingPrice: dec.clearingPrice,
+      marginalBidQuantity: dec.marginalBidQuantity,
+      marginalAskQuantity: dec.marginalAskQuantity,
+      baseSold: dec.baseSold,
+      quoteProceeds: dec.quoteProceeds,
+      hasWithdrawnProceeds: dec.hasWithdrawnProceeds,
+      isCancelled: dec.isCancelled,
+      numOpenOrders: dec.numOpenOrders,
+      baseVestingUnclaimed: dec.baseVestingUnclaimed,
     })
   }
 
@@ -286,9 +560,23 @@ export class Auction {
       startOrderPhase: this.startOrderPhase.toString(),
       endOrderPhase: this.endOrderPhase.toString(),
       endDecryptionPhase: this.endDecryptionPhase.toString(),
+      softCloseWindow: this.softCloseWindow.toString(),
+      softCloseExtension: this.softCloseExtension.toString(),
+      maxSoftCloseExtension: this.maxSoftCloseExtension.toString(),
+      totalSoftCloseExtension: this.totalSoftCloseExtension.toString(),
       areAsksEncrypted: this.areAsksEncrypted,
       areBidsEncrypted: this.areBidsEncrypted,
       naclPubkey: this.naclPubkey,
+      isCommitReveal: this.isCommitReveal,
+      unrevealedPenaltyBps: this.unrevealedPenaltyBps,
+      committee: this.committee.map((item) => item.toString()),
+      committeeThreshold: this.committeeThreshold,
+      committeePublicShares: this.committeePublicShares,
+      keyShares: this.keyShares.map((item) => item.toJSON()),
+      committeeSecretKey: this.committeeSecretKey,
+      finalPriceType: this.finalPriceType.toJSON(),
+      fillAllocationType: this.fillAllocationType.toJSON(),
+      isPayAsBid: this.isPayAsBid,
       eventQueue: this.eventQueue.toString(),
       bids: this.bids.toString(),
       asks: this.asks.toString(),
@@ -296,8 +584,22 @@ export class Auction {
       baseMint: this.baseMint.toString(),
       quoteVault: this.quoteVault.toString(),
       baseVault: this.baseVault.toString(),
+      quoteTokenProgram: this.quoteTokenProgram.toString(),
+      baseTokenProgram: this.baseTokenProgram.toString(),
+      baseLotSize: this.baseLotSize.toString(),
+      quoteLotSize: this.quoteLotSize.toString(),
       minBaseOrderSize: this.minBaseOrderSize.toString(),
       tickSize: this.tickSize.toString(),
+      maxBasePerUser: this.maxBasePerUser.toString(),
+      allowlistRoot: this.allowlistRoot,
+      reservePrice: this.reservePrice.toString(),
+      reservePriceCommitment: this.reservePriceCommitment,
+      minBaseMatched: this.minBaseMatched.toString(),
+      minQuoteRaised: this.minQuoteRaised.toString(),
+      fixedBaseSupply: this.fixedBaseSupply.toString(),
+      hasDepositedFixedSupply: this.hasDepositedFixedSupply,
+      vestingCliff: this.vestingCliff.toString(),
+      vestingDuration: this.vestingDuration.toString(),
       askSearchStackDepth: this.askSearchStackDepth,
       bidSearchStackDepth: this.bidSearchStackDepth,
       askSearchStackValues: this.askSearchStackValues,
@@ -307,13 +609,32 @@ export class Auction {
       currentBidQuantityFilled: this.currentBidQuantityFilled.toString(),
       currentAskQuantityFilled: this.currentAskQuantityFilled.toString(),
       totalQuantityFilledSoFar: this.totalQuantityFilledSoFar.toString(),
+      currentBidLevelQuantity: this.currentBidLevelQuantity.toString(),
+      currentAskLevelQuantity: this.currentAskLevelQuantity.toString(),
+      filledBidLevelsQuantity: this.filledBidLevelsQuantity.toString(),
+      filledAskLevelsQuantity: this.filledAskLevelsQuantity.toString(),
+      hasCrossed: this.hasCrossed,
+      isBidLevelComplete: this.isBidLevelComplete,
+      isAskLevelComplete: this.isAskLevelComplete,
+      firstLosingBidPrice:
+        (this.firstLosingBidPrice && this.firstLosingBidPrice.toString()) ||
+        null,
       hasFoundClearingPrice: this.hasFoundClearingPrice,
+      hasFailed: this.hasFailed,
       totalQuantityMatched: this.totalQuantityMatched.toString(),
       remainingAskFills: this.remainingAskFills.toString(),
       remainingBidFills: this.remainingBidFills.toString(),
       finalBidPrice: this.finalBidPrice.toString(),
       finalAskPrice: this.finalAskPrice.toString(),
       clearingPrice: this.clearingPrice.toString(),
+      marginalBidQuantity: this.marginalBidQuantity.toString(),
+      marginalAskQuantity: this.marginalAskQuantity.toString(),
+      baseSold: this.baseSold.toString(),
+      quoteProceeds: this.quoteProceeds.toString(),
+      hasWithdrawnProceeds: this.hasWithdrawnProceeds,
+      isCancelled: this.isCancelled,
+      numOpenOrders: this.numOpenOrders,
+      baseVestingUnclaimed: this.baseVestingUnclaimed.toString(),
     }
   }
 
@@ -326,9 +647,25 @@ export class Auction {
       startOrderPhase: new BN(obj.startOrderPhase),
       endOrderPhase: new BN(obj.endOrderPhase),
       endDecryptionPhase: new BN(obj.endDecryptionPhase),
+      softCloseWindow: new BN(obj.softCloseWindow),
+      softCloseExtension: new BN(obj.softCloseExtension),
+      maxSoftCloseExtension: new BN(obj.maxSoftCloseExtension),
+      totalSoftCloseExtension: new BN(obj.totalSoftCloseExtension),
       areAsksEncrypted: obj.areAsksEncrypted,
       areBidsEncrypted: obj.areBidsEncrypted,
       naclPubkey: obj.naclPubkey,
+      isCommitReveal: obj.isCommitReveal,
+      unrevealedPenaltyBps: obj.unrevealedPenaltyBps,
+      committee: obj.committee.map((item) => new PublicKey(item)),
+      committeeThreshold: obj.committeeThreshold,
+      committeePublicShares: obj.committeePublicShares,
+      keyShares: obj.keyShares.map((item) => types.KeyShare.fromJSON(item)),
+      committeeSecretKey: obj.committeeSecretKey,
+      finalPriceType: types.FinalPriceTypes.fromJSON(obj.finalPriceType),
+      fillAllocationType: types.FillAllocationTypes.fromJSON(
+        obj.fillAllocationType
+      ),
+      isPayAsBid: obj.isPayAsBid,
       eventQueue: new PublicKey(obj.eventQueue),
       bids: new PublicKey(obj.bids),
       asks: new PublicKey(obj.asks),
@@ -336,8 +673,22 @@ export class Auction {
       baseMint: new PublicKey(obj.baseMint),
       quoteVault: new PublicKey(obj.quoteVault),
       baseVault: new PublicKey(obj.baseVault),
+      quoteTokenProgram: new PublicKey(obj.quoteTokenProgram),
+      baseTokenProgram: new PublicKey(obj.baseTokenProgram),
+      baseLotSize: new BN(obj.baseLotSize),
+      quoteLotSize: new BN(obj.quoteLotSize),
       minBaseOrderSize: new BN(obj.minBaseOrderSize),
       tickSize: new BN(obj.tickSize),
+      maxBasePerUser: new BN(obj.maxBasePerUser),
+      allowlistRoot: obj.allowlistRoot,
+      reservePrice: new BN(obj.reservePrice),
+      reservePriceCommitment: obj.reservePriceCommitment,
+      minBaseMatched: new BN(obj.minBaseMatched),
+      minQuoteRaised: new BN(obj.minQuoteRaised),
+      fixedBaseSupply: new BN(obj.fixedBaseSupply),
+      hasDepositedFixedSupply: obj.hasDepositedFixedSupply,
+      vestingCliff: new BN(obj.vestingCliff),
+      vestingDuration: new BN(obj.vestingDuration),
       askSearchStackDepth: obj.askSearchStackDepth,
       bidSearchStackDepth: obj.bidSearchStackDepth,
       askSearchStackValues: obj.askSearchStackValues,
@@ -347,13 +698,31 @@ export class Auction {
       currentBidQuantityFilled: new BN(obj.currentBidQuantityFilled),
       currentAskQuantityFilled: new BN(obj.currentAskQuantityFilled),
       totalQuantityFilledSoFar: new BN(obj.totalQuantityFilledSoFar),
+      currentBidLevelQuantity: new BN(obj.currentBidLevelQuantity),
+      currentAskLevelQuantity: new BN(obj.currentAskLevelQuantity),
+      filledBidLevelsQuantity: new BN(obj.filledBidLevelsQuantity),
+      filledAskLevelsQuantity: new BN(obj.filledAskLevelsQuantity),
+      hasCrossed: obj.hasCrossed,
+      isBidLevelComplete: obj.isBidLevelComplete,
+      isAskLevelComplete: obj.isAskLevelComplete,
+      firstLosingBidPrice:
+        (obj.firstLosingBidPrice && new BN(obj.firstLosingBidPrice)) || null,
       hasFoundClearingPrice: obj.hasFoundClearingPrice,
+      hasFailed: obj.hasFailed,
       totalQuantityMatched: new BN(obj.totalQuantityMatched),
       remainingAskFills: new BN(obj.remainingAskFills),
       remainingBidFills: new BN(obj.remainingBidFills),
       finalBidPrice: new BN(obj.finalBidPrice),
       finalAskPrice: new BN(obj.finalAskPrice),
       clearingPrice: new BN(obj.clearingPrice),
+      marginalBidQuantity: new BN(obj.marginalBidQuantity),
+      marginalAskQuantity: new BN(obj.marginalAskQuantity),
+      baseSold: new BN(obj.baseSold),
+      quoteProceeds: new BN(obj.quoteProceeds),
+      hasWithdrawnProceeds: obj.hasWithdrawnProceeds,
+      isCancelled: obj.isCancelled,
+      numOpenOrders: obj.numOpenOrders,
+      baseVestingUnclaimed: new BN(obj.baseVestingUnclaimed),
     })
   }
 }
@@ -19,6 +19,10 @@ export interface OpenOrdersFields {
   baseTokenFree: BN
   numOrders: number
   orders: Array<BN>
+  orderRecords: Array<types.OrderRecordFields>
+  maxBaseAllocation: BN
+  baseQtyOrdered: BN
+  baseQtyFilled: BN
 }
 
 export interface OpenOrdersJSON {
@@ -36,6 +40,10 @@ export interface OpenOrdersJSON {
   baseTokenFree: string
   numOrders: number
   orders: Array<string>
+  orderRecords: Array<types.OrderRecordJSON>
+  maxBaseAllocation: string
+  baseQtyOrdered: string
+  baseQtyFilled: string
 }
 
 export class OpenOrders {
@@ -53,6 +61,10 @@ export class OpenOrders {
   readonly baseTokenFree: BN
   readonly numOrders: number
   readonly orders: Array<BN>
+  readonly orderRecords: Array<types.OrderRecord>
+  readonly maxBaseAllocation: BN
+  readonly baseQtyOrdered: BN
+  readonly baseQtyFilled: BN
 
   static readonly discriminator = Buffer.from([
     139, 166, 123, 206, 111, 2, 116, 33,
@@ -73,6 +85,10 @@ export class OpenOrders {
     borsh.u64("baseTokenFree"),
     borsh.u8("numOrders"),
     borsh.vec(borsh.u128(), "orders"),
+    borsh.vec(types.OrderRecord.layout(), "orderRecords"),
+    borsh.u64("maxBaseAllocation"),
+    borsh.u64("baseQtyOrdered"),
+    borsh.u64("baseQtyFilled"),
   ])
 
   constructor(fields: OpenOrdersFields) {
@@ -92,6 +108,12 @@ export class OpenOrders {
     this.baseTokenFree = fields.baseTokenFree
     this.numOrders = fields.numOrders
     this.orders = fields.orders
+    this.orderRecords = fields.orderRecords.map(
+      (item) => new types.OrderRecord({ ...item })
+    )
+    this.maxBaseAllocation = fields.maxBaseAllocation
+    this.baseQtyOrdered = fields.baseQtyOrdered
+    this.baseQtyFilled = fields.baseQtyFilled
   }
 
   static async fetch(
@@ -152,6 +174,12 @@ export class OpenOrders {
       baseTokenFree: dec.baseTokenFree,
       numOrders: dec.numOrders,
       orders: dec.orders,
+      orderRecords: dec.orderRecords.map((item) =>
+        types.OrderRecord.fromDecoded(item)
+      ),
+      maxBaseAllocation: dec.maxBaseAllocation,
+      baseQtyOrdered: dec.baseQtyOrdered,
+      baseQtyFilled: dec.baseQtyFilled,
     })
   }
 
@@ -171,6 +199,10 @@ export class OpenOrders {
       baseTokenFree: this.baseTokenFree.toString(),
       numOrders: this.numOrders,
       orders: this.orders.map((item) => item.toString()),
+      orderRecords: this.orderRecords.map((item) => item.toJSON()),
+      maxBaseAllocation: this.maxBaseAllocation.toString(),
+      baseQtyOrdered: this.baseQtyOrdered.toString(),
+      baseQtyFilled: this.baseQtyFilled.toString(),
     }
   }
 
@@ -192,6 +224,12 @@ export class OpenOrders {
       baseTokenFree: new BN(obj.baseTokenFree),
       numOrders: obj.numOrders,
       orders: obj.orders.map((item) => new BN(item)),
+      orderRecords: obj.orderRecords.map((item) =>
+        types.OrderRecord.fromJSON(item)
+      ),
+      maxBaseAllocation: new BN(obj.maxBaseAllocation),
+      baseQtyOrdered: new BN(obj.baseQtyOrdered),
+      baseQtyFilled: new BN(obj.baseQtyFilled),
     })
   }
 }
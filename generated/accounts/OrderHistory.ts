@@ -10,6 +10,8 @@ export interface OrderHistoryFields {
   side: types.SideKind
   quoteAmountReturned: BN
   baseAmountReturned: BN
+  clearingPrice: BN
+  orders: Array<types.OrderRecordFields>
 }
 
 export interface OrderHistoryJSON {
@@ -18,6 +20,8 @@ export interface OrderHistoryJSON {
   side: types.SideJSON
   quoteAmountReturned: string
   baseAmountReturned: string
+  clearingPrice: string
+  orders: Array<types.OrderRecordJSON>
 }
 
 export class OrderHistory {
@@ -26,6 +30,8 @@ export class OrderHistory {
   readonly side: types.SideKind
   readonly quoteAmountReturned: BN
   readonly baseAmountReturned: BN
+  readonly clearingPrice: BN
+  readonly orders: Array<types.OrderRecord>
 
   static readonly discriminator = Buffer.from([33, 107, 40, 81, 11, 0, 245, 31])
 
@@ -35,6 +41,8 @@ export class OrderHistory {
     types.Side.layout("side"),
     borsh.u64("quoteAmountReturned"),
     borsh.u64("baseAmountReturned"),
+    borsh.u64("clearingPrice"),
+    borsh.vec(types.OrderRecord.layout(), "orders"),
   ])
 
   constructor(fields: OrderHistoryFields) {
@@ -43,6 +51,10 @@ export class OrderHistory {
     this.side = fields.side
     this.quoteAmountReturned = fields.quoteAmountReturned
     this.baseAmountReturned = fields.baseAmountReturned
+    this.clearingPrice = fields.clearingPrice
+    this.orders = fields.orders.map(
+      (item) => new types.OrderRecord({ ...item })
+    )
   }
 
   static async fetch(
@@ -92,6 +104,8 @@ export class OrderHistory {
       side: types.Side.fromDecoded(dec.side),
       quoteAmountReturned: dec.quoteAmountReturned,
       baseAmountReturned: dec.baseAmountReturned,
+      clearingPrice: dec.clearingPrice,
+      orders: dec.orders.map((item) => types.OrderRecord.fromDecoded(item)),
     })
   }
 
@@ -102,6 +116,8 @@ export class OrderHistory {
       side: this.side.toJSON(),
       quoteAmountReturned: this.quoteAmountReturned.toString(),
       baseAmountReturned: this.baseAmountReturned.toString(),
+      clearingPrice: this.clearingPrice.toString(),
+      orders: this.orders.map((item) => item.toJSON()),
     }
   }
 
@@ -112,6 +128,8 @@ export class OrderHistory {
       side: types.Side.fromJSON(obj.side),
       quoteAmountReturned: new BN(obj.quoteAmountReturned),
       baseAmountReturned: new BN(obj.baseAmountReturned),
+      clearingPrice: new BN(obj.clearingPrice),
+      orders: obj.orders.map((item) => types.OrderRecord.fromJSON(item)),
     })
   }
 }
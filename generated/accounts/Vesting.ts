@@ -0,0 +1,111 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VestingFields {
+  bump: number
+  auction: PublicKey
+  baseAmountVesting: BN
+  baseAmountClaimed: BN
+}
+
+export interface VestingJSON {
+  bump: number
+  auction: string
+  baseAmountVesting: string
+  baseAmountClaimed: string
+}
+
+export class Vesting {
+  readonly bump: number
+  readonly auction: PublicKey
+  readonly baseAmountVesting: BN
+  readonly baseAmountClaimed: BN
+
+  static readonly discriminator = Buffer.from([
+    100, 149, 66, 138, 95, 200, 128, 241,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u8("bump"),
+    borsh.publicKey("auction"),
+    borsh.u64("baseAmountVesting"),
+    borsh.u64("baseAmountClaimed"),
+  ])
+
+  constructor(fields: VestingFields) {
+    this.bump = fields.bump
+    this.auction = fields.auction
+    this.baseAmountVesting = fields.baseAmountVesting
+    this.baseAmountClaimed = fields.baseAmountClaimed
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey
+  ): Promise<Vesting | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(PROGRAM_ID)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[]
+  ): Promise<Array<Vesting | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(PROGRAM_ID)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Vesting {
+    if (!data.slice(0, 8).equals(Vesting.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Vesting.layout.decode(data.slice(8))
+
+    return new Vesting({
+      bump: dec.bump,
+      auction: dec.auction,
+      baseAmountVesting: dec.baseAmountVesting,
+      baseAmountClaimed: dec.baseAmountClaimed,
+    })
+  }
+
+  toJSON(): VestingJSON {
+    return {
+      bump: this.bump,
+      auction: this.auction.toString(),
+      baseAmountVesting: this.baseAmountVesting.toString(),
+      baseAmountClaimed: this.baseAmountClaimed.toString(),
+    }
+  }
+
+  static fromJSON(obj: VestingJSON): Vesting {
+    return new Vesting({
+      bump: obj.bump,
+      auction: new PublicKey(obj.auction),
+      baseAmountVesting: new BN(obj.baseAmountVesting),
+      baseAmountClaimed: new BN(obj.baseAmountClaimed),
+    })
+  }
+}
@@ -5,3 +5,4 @@ export {
   OrderHistoryFields,
   OrderHistoryJSON,
 } from "./OrderHistory"
+export { Vesting, VestingFields, VestingJSON } from "./Vesting"
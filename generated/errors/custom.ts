@@ -42,6 +42,46 @@ export type CustomError =
   | EventQueueNotEmpty
   | NumericalOverflow
   | SlabIteratorOverflow
+  | IncompatibleMintDecimals
+  | ReservePriceNotAMultipleOfTickSize
+  | InvalidReservePrice
+  | NoEncryptedReservePrice
+  | InvalidReservePriceKey
+  | AskOrdersNotAllowed
+  | NotFixedSupplyAuction
+  | FixedSupplyAlreadyDeposited
+  | ProceedsAlreadyWithdrawn
+  | MaxBasePerUserExceeded
+  | InvalidSoftCloseParams
+  | InvalidAllowlistProof
+  | InvalidVestingParams
+  | NoVestingSchedule
+  | NoVestedTokensToClaim
+  | AuctionCancelled
+  | AuctionAlreadyStarted
+  | AuctionIdCannotChange
+  | InvalidLotSize
+  | InvalidTokenProgram
+  | UnsupportedMintExtension
+  | QuoteBudgetOnlyForBids
+  | OpenOrdersNotSettled
+  | ProceedsNotWithdrawn
+  | InvalidSettleAccounts
+  | AobAccountsNotClosed
+  | VaultsNotEmpty
+  | InvalidCommitRevealParams
+  | CommitRevealAuctionsOnly
+  | NotAvailableForCommitReveal
+  | InvalidCommitment
+  | InvalidCommitteeParams
+  | CommitteeSizeCannotChange
+  | NotACommitteeMember
+  | KeyShareAlreadySubmitted
+  | NotEnoughKeyShares
+  | VestedTokensNotClaimed
+  | InvalidKeyShare
+  | NoOrdersToPlace
+  | AuctionHasOpenOrders
 
 export class NotImplemented extends Error {
   readonly code = 6000
@@ -500,6 +540,448 @@ export class SlabIteratorOverflow extends Error {
   }
 }
 
+export class IncompatibleMintDecimals extends Error {
+  readonly code = 6043
+  readonly name = "IncompatibleMintDecimals"
+  readonly msg = "Base & quote mint need to have the same decimals"
+
+  constructor() {
+    super("6043: Base & quote mint need to have the same decimals")
+  }
+}
+
+export class ReservePriceNotAMultipleOfTickSize extends Error {
+  readonly code = 6044
+  readonly name = "ReservePriceNotAMultipleOfTickSize"
+  readonly msg = "Reserve price must be a multiple of the assigned tick size"
+
+  constructor() {
+    super("6044: Reserve price must be a multiple of the assigned tick size")
+  }
+}
+
+export class InvalidReservePrice extends Error {
+  readonly code = 6045
+  readonly name = "InvalidReservePrice"
+  readonly msg =
+    "Auction can have either a public or a hidden reserve price, not both"
+
+  constructor() {
+    super(
+      "6045: Auction can have either a public or a hidden reserve price, not both"
+    )
+  }
+}
+
+export class NoEncryptedReservePrice extends Error {
+  readonly code = 6046
+  readonly name = "NoEncryptedReservePrice"
+  readonly msg = "Auction doesn't have a hidden reserve price to reveal"
+
+  constructor() {
+    super("6046: Auction doesn't have a hidden reserve price to reveal")
+  }
+}
+
+export class InvalidReservePriceKey extends Error {
+  readonly code = 6047
+  readonly name = "InvalidReservePriceKey"
+  readonly msg =
+    "Reserve price and salt don't match the reserve price commitment"
+
+  constructor() {
+    super(
+      "6047: Reserve price and salt don't match the reserve price commitment"
+    )
+  }
+}
+
+export class AskOrdersNotAllowed extends Error {
+  readonly code = 6048
+  readonly name = "AskOrdersNotAllowed"
+  readonly msg = "Fixed supply auctions don't take ask orders"
+
+  constructor() {
+    super("6048: Fixed supply auctions don't take ask orders")
+  }
+}
+
+export class NotFixedSupplyAuction extends Error {
+  readonly code = 6049
+  readonly name = "NotFixedSupplyAuction"
+  readonly msg = "This is not a fixed supply auction"
+
+  constructor() {
+    super("6049: This is not a fixed supply auction")
+  }
+}
+
+export class FixedSupplyAlreadyDeposited extends Error {
+  readonly code = 6050
+  readonly name = "FixedSupplyAlreadyDeposited"
+  readonly msg = "Fixed supply has already been deposited"
+
+  constructor() {
+    super("6050: Fixed supply has already been deposited")
+  }
+}
+
+export class ProceedsAlreadyWithdrawn extends Error {
+  readonly code = 6051
+  readonly name = "ProceedsAlreadyWithdrawn"
+  readonly msg = "Auction proceeds have already been withdrawn"
+
+  constructor() {
+    super("6051: Auction proceeds have already been withdrawn")
+  }
+}
+
+export class MaxBasePerUserExceeded extends Error {
+  readonly code = 6052
+  readonly name = "MaxBasePerUserExceeded"
+  readonly msg =
+    "Orders add up to more than the maximum base quantity allowed per user"
+
+  constructor() {
+    super(
+      "6052: Orders add up to more than the maximum base quantity allowed per user"
+    )
+  }
+}
+
+export class InvalidSoftCloseParams extends Error {
+  readonly code = 6053
+  readonly name = "InvalidSoftCloseParams"
+  readonly msg = "Soft close window and extensions can't be negative"
+
+  constructor() {
+    super("6053: Soft close window and extensions can't be negative")
+  }
+}
+
+export class InvalidAllowlistProof extends Error {
+  readonly code = 6054
+  readonly name = "InvalidAllowlistProof"
+  readonly msg = "Allowlist proof doesn't match the auction's allowlist root"
+
+  constructor() {
+    super("6054: Allowlist proof doesn't match the auction's allowlist root")
+  }
+}
+
+export class InvalidVestingParams extends Error {
+  readonly code = 6055
+  readonly name = "InvalidVestingParams"
+  readonly msg =
+    "Vesting cliff and duration can't be negative and the cliff can't be longer than the duration"
+
+  constructor() {
+    super(
+      "6055: Vesting cliff and duration can't be negative and the cliff can't be longer than the duration"
+    )
+  }
+}
+
+export class NoVestingSchedule extends Error {
+  readonly code = 6056
+  readonly name = "NoVestingSchedule"
+  readonly msg = "Auction doesn't have a vesting schedule"
+
+  constructor() {
+    super("6056: Auction doesn't have a vesting schedule")
+  }
+}
+
+export class NoVestedTokensToClaim extends Error {
+  readonly code = 6057
+  readonly name = "NoVestedTokensToClaim"
+  readonly msg = "No vested tokens left to claim"
+
+  constructor() {
+    super("6057: No vested tokens left to claim")
+  }
+}
+
+export class AuctionCancelled extends Error {
+  readonly code = 6058
+  readonly name = "AuctionCancelled"
+  readonly msg = "Auction has been cancelled"
+
+  constructor() {
+    super("6058: Auction has been cancelled")
+  }
+}
+
+export class AuctionAlreadyStarted extends Error {
+  readonly code = 6059
+  readonly name = "AuctionAlreadyStarted"
+  readonly msg = "Auction can only be updated before the order phase starts"
+
+  constructor() {
+    super("6059: Auction can only be updated before the order phase starts")
+  }
+}
+
+export class AuctionIdCannotChange extends Error {
+  readonly code = 6060
+  readonly name = "AuctionIdCannotChange"
+  readonly msg =
+    "Auction id is part of the auction's address and can't be changed"
+
+  constructor() {
+    super(
+      "6060: Auction id is part of the auction's address and can't be changed"
+    )
+  }
+}
+
+export class InvalidLotSize extends Error {
+  readonly code = 6061
+  readonly name = "InvalidLotSize"
+  readonly msg = "Base and quote lot sizes should be greater than zero"
+
+  constructor() {
+    super("6061: Base and quote lot sizes should be greater than zero")
+  }
+}
+
+export class InvalidTokenProgram extends Error {
+  readonly code = 6062
+  readonly name = "InvalidTokenProgram"
+  readonly msg =
+    "Token accounts and mints need to belong to the token program or token-2022"
+
+  constructor() {
+    super(
+      "6062: Token accounts and mints need to belong to the token program or token-2022"
+    )
+  }
+}
+
+export class UnsupportedMintExtension extends Error {
+  readonly code = 6063
+  readonly name = "UnsupportedMintExtension"
+  readonly msg =
+    "Only the transfer fee and mint close authority token-2022 extensions are supported"
+
+  constructor() {
+    super(
+      "6063: Only the transfer fee and mint close authority token-2022 extensions are supported"
+    )
+  }
+}
+
+export class QuoteBudgetOnlyForBids extends Error {
+  readonly code = 6064
+  readonly name = "QuoteBudgetOnlyForBids"
+  readonly msg = "Only bids can be placed with a quote budget"
+
+  constructor() {
+    super("6064: Only bids can be placed with a quote budget")
+  }
+}
+
+export class OpenOrdersNotSettled extends Error {
+  readonly code = 6065
+  readonly name = "OpenOrdersNotSettled"
+  readonly msg = "Every open orders account needs to be settled first"
+
+  constructor() {
+    super("6065: Every open orders account needs to be settled first")
+  }
+}
+
+export class ProceedsNotWithdrawn extends Error {
+  readonly code = 6066
+  readonly name = "ProceedsNotWithdrawn"
+  readonly msg = "Auction proceeds need to be withdrawn first"
+
+  constructor() {
+    super("6066: Auction proceeds need to be withdrawn first")
+  }
+}
+
+export class InvalidSettleAccounts extends Error {
+  readonly code = 6067
+  readonly name = "InvalidSettleAccounts"
+  readonly msg =
+    "Remaining accounts don't match the (user, open orders, order history, vesting, user quote, user base) layout"
+
+  constructor() {
+    super(
+      "6067: Remaining accounts don't match the (user, open orders, order history, vesting, user quote, user base) layout"
+    )
+  }
+}
+
+export class AobAccountsNotClosed extends Error {
+  readonly code = 6068
+  readonly name = "AobAccountsNotClosed"
+  readonly msg = "AOB accounts need to be closed first"
+
+  constructor() {
+    super("6068: AOB accounts need to be closed first")
+  }
+}
+
+export class VaultsNotEmpty extends Error {
+  readonly code = 6069
+  readonly name = "VaultsNotEmpty"
+  readonly msg =
+    "Vaults need to be empty, withdraw the proceeds and sweep the dust first"
+
+  constructor() {
+    super(
+      "6069: Vaults need to be empty, withdraw the proceeds and sweep the dust first"
+    )
+  }
+}
+
+export class InvalidCommitRevealParams extends Error {
+  readonly code = 6070
+  readonly name = "InvalidCommitRevealParams"
+  readonly msg =
+    "Commit reveal auctions need an encrypted side and the penalty can't be over 10000 bps"
+
+  constructor() {
+    super(
+      "6070: Commit reveal auctions need an encrypted side and the penalty can't be over 10000 bps"
+    )
+  }
+}
+
+export class CommitRevealAuctionsOnly extends Error {
+  readonly code = 6071
+  readonly name = "CommitRevealAuctionsOnly"
+  readonly msg = "Only available in commit reveal auctions"
+
+  constructor() {
+    super("6071: Only available in commit reveal auctions")
+  }
+}
+
+export class NotAvailableForCommitReveal extends Error {
+  readonly code = 6072
+  readonly name = "NotAvailableForCommitReveal"
+  readonly msg = "Orders in commit reveal auctions are revealed by their users"
+
+  constructor() {
+    super("6072: Orders in commit reveal auctions are revealed by their users")
+  }
+}
+
+export class InvalidCommitment extends Error {
+  readonly code = 6073
+  readonly name = "InvalidCommitment"
+  readonly msg = "Revealed order doesn't match its commitment"
+
+  constructor() {
+    super("6073: Revealed order doesn't match its commitment")
+  }
+}
+
+export class InvalidCommitteeParams extends Error {
+  readonly code = 6074
+  readonly name = "InvalidCommitteeParams"
+  readonly msg =
+    "Committee can have at most 10 unique members with valid public shares and the threshold should be between 1 and the committee size"
+
+  constructor() {
+    super(
+      "6074: Committee can have at most 10 unique members with valid public shares and the threshold should be between 1 and the committee size"
+    )
+  }
+}
+
+export class CommitteeSizeCannotChange extends Error {
+  readonly code = 6075
+  readonly name = "CommitteeSizeCannotChange"
+  readonly msg =
+    "Committee size is part of the auction account's size and can't be changed"
+
+  constructor() {
+    super(
+      "6075: Committee size is part of the auction account's size and can't be changed"
+    )
+  }
+}
+
+export class NotACommitteeMember extends Error {
+  readonly code = 6076
+  readonly name = "NotACommitteeMember"
+  readonly msg = "Signer isn't a member of the auction's committee"
+
+  constructor() {
+    super("6076: Signer isn't a member of the auction's committee")
+  }
+}
+
+export class KeyShareAlreadySubmitted extends Error {
+  readonly code = 6077
+  readonly name = "KeyShareAlreadySubmitted"
+  readonly msg = "Key share has already been submitted"
+
+  constructor() {
+    super("6077: Key share has already been submitted")
+  }
+}
+
+export class NotEnoughKeyShares extends Error {
+  readonly code = 6078
+  readonly name = "NotEnoughKeyShares"
+  readonly msg =
+    "Not enough committee key shares have been submitted to decrypt"
+
+  constructor() {
+    super(
+      "6078: Not enough committee key shares have been submitted to decrypt"
+    )
+  }
+}
+
+export class VestedTokensNotClaimed extends Error {
+  readonly code = 6079
+  readonly name = "VestedTokensNotClaimed"
+  readonly msg =
+    "Vesting account can only be closed once all of its base has been claimed"
+
+  constructor() {
+    super(
+      "6079: Vesting account can only be closed once all of its base has been claimed"
+    )
+  }
+}
+
+export class InvalidKeyShare extends Error {
+  readonly code = 6080
+  readonly name = "InvalidKeyShare"
+  readonly msg = "Key share doesn't match the member's public share"
+
+  constructor() {
+    super("6080: Key share doesn't match the member's public share")
+  }
+}
+
+export class NoOrdersToPlace extends Error {
+  readonly code = 6081
+  readonly name = "NoOrdersToPlace"
+  readonly msg = "At least one order needs to be placed"
+
+  constructor() {
+    super("6081: At least one order needs to be placed")
+  }
+}
+
+export class AuctionHasOpenOrders extends Error {
+  readonly code = 6082
+  readonly name = "AuctionHasOpenOrders"
+  readonly msg = "Auction can't be updated once users have open orders accounts"
+
+  constructor() {
+    super("6082: Auction can't be updated once users have open orders accounts")
+  }
+}
+
 export function fromCode(code: number): CustomError | null {
   switch (code) {
     case 6000:
@@ -588,6 +1070,86 @@ export function fromCode(code: number): CustomError | null {
       return new NumericalOverflow()
     case 6042:
       return new SlabIteratorOverflow()
+    case 6043:
+      return new IncompatibleMintDecimals()
+    case 6044:
+      return new ReservePriceNotAMultipleOfTickSize()
+    case 6045:
+      return new InvalidReservePrice()
+    case 6046:
+      return new NoEncryptedReservePrice()
+    case 6047:
+      return new InvalidReservePriceKey()
+    case 6048:
+      return new AskOrdersNotAllowed()
+    case 6049:
+      return new NotFixedSupplyAuction()
+    case 6050:
+      return new FixedSupplyAlreadyDeposited()
+    case 6051:
+      return new ProceedsAlreadyWithdrawn()
+    case 6052:
+      return new MaxBasePerUserExceeded()
+    case 6053:
+      return new InvalidSoftCloseParams()
+    case 6054:
+      return new InvalidAllowlistProof()
+    case 6055:
+      return new InvalidVestingParams()
+    case 6056:
+      return new NoVestingSchedule()
+    case 6057:
+      return new NoVestedTokensToClaim()
+    case 6058:
+      return new AuctionCancelled()
+    case 6059:
+      return new AuctionAlreadyStarted()
+    case 6060:
+      return new AuctionIdCannotChange()
+    case 6061:
+      return new InvalidLotSize()
+    case 6062:
+      return new InvalidTokenProgram()
+    case 6063:
+      return new UnsupportedMintExtension()
+    case 6064:
+      return new QuoteBudgetOnlyForBids()
+    case 6065:
+      return new OpenOrdersNotSettled()
+    case 6066:
+      return new ProceedsNotWithdrawn()
+    case 6067:
+      return new InvalidSettleAccounts()
+    case 6068:
+      return new AobAccountsNotClosed()
+    case 6069:
+      return new VaultsNotEmpty()
+    case 6070:
+      return new InvalidCommitRevealParams()
+    case 6071:
+      return new CommitRevealAuctionsOnly()
+    case 6072:
+      return new NotAvailableForCommitReveal()
+    case 6073:
+      return new InvalidCommitment()
+    case 6074:
+      return new InvalidCommitteeParams()
+    case 6075:
+      return new CommitteeSizeCannotChange()
+    case 6076:
+      return new NotACommitteeMember()
+    case 6077:
+      return new KeyShareAlreadySubmitted()
+    case 6078:
+      return new NotEnoughKeyShares()
+    case 6079:
+      return new VestedTokensNotClaimed()
+    case 6080:
+      return new InvalidKeyShare()
+    case 6081:
+      return new NoOrdersToPlace()
+    case 6082:
+      return new AuctionHasOpenOrders()
   }
 
   return null
@@ -4,9 +4,17 @@ import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript
 import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import { PROGRAM_ID } from "../programId"
 
-export function initialize() {
-  const keys = []
-  const identifier = Buffer.from([175, 175, 109, 31, 13, 152, 155, 237])
+export interface AbortAuctionAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+}
+
+export function abortAuction(accounts: AbortAuctionAccounts) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([97, 4, 86, 156, 252, 84, 9, 186])
   const data = identifier
   const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
   return ix
@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CancelAllOrdersAccounts {
+  user: PublicKey
+  auction: PublicKey
+  openOrders: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+  quoteMint: PublicKey
+  baseMint: PublicKey
+  userQuote: PublicKey
+  userBase: PublicKey
+  quoteVault: PublicKey
+  baseVault: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export function cancelAllOrders(accounts: CancelAllOrdersAccounts) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.bids, isSigner: false, isWritable: true },
+    { pubkey: accounts.asks, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.userQuote, isSigner: false, isWritable: true },
+    { pubkey: accounts.userBase, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([196, 83, 243, 171, 17, 100, 160, 143])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
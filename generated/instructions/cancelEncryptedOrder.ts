@@ -18,7 +18,8 @@ export interface CancelEncryptedOrderAccounts {
   userBase: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
 }
 
 export const layout = borsh.struct([borsh.u8("orderIdx")])
@@ -37,7 +38,8 @@ export function cancelEncryptedOrder(
     { pubkey: accounts.userBase, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([165, 25, 96, 117, 118, 214, 218, 85])
   const buffer = Buffer.alloc(1000)
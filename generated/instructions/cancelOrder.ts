@@ -21,7 +21,8 @@ export interface CancelOrderAccounts {
   userBase: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
 }
 
 export const layout = borsh.struct([borsh.u128("orderId")])
@@ -32,7 +33,7 @@ export function cancelOrder(
 ) {
   const keys = [
     { pubkey: accounts.user, isSigner: true, isWritable: false },
-    { pubkey: accounts.auction, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
     { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
     { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
     { pubkey: accounts.bids, isSigner: false, isWritable: true },
@@ -43,7 +44,8 @@ export function cancelOrder(
     { pubkey: accounts.userBase, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([95, 129, 237, 240, 8, 49, 223, 132])
   const buffer = Buffer.alloc(1000)
@@ -0,0 +1,31 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimVestedAccounts {
+  user: PublicKey
+  auction: PublicKey
+  vesting: PublicKey
+  baseVault: PublicKey
+  baseMint: PublicKey
+  userBase: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export function claimVested(accounts: ClaimVestedAccounts) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.vesting, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.userBase, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([208, 190, 166, 114, 203, 225, 140, 208])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
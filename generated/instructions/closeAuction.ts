@@ -0,0 +1,39 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseAuctionAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+  quoteMint: PublicKey
+  baseMint: PublicKey
+  quoteVault: PublicKey
+  baseVault: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export function closeAuction(accounts: CloseAuctionAccounts) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: false, isWritable: true },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: false },
+    { pubkey: accounts.bids, isSigner: false, isWritable: false },
+    { pubkey: accounts.asks, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([225, 129, 91, 48, 215, 73, 203, 172])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
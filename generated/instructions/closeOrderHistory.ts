@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseOrderHistoryArgs {
+  auctionId: Array<number>
+  auctioneer: PublicKey
+}
+
+export interface CloseOrderHistoryAccounts {
+  user: PublicKey
+  openOrders: PublicKey
+  orderHistory: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 10, "auctionId"),
+  borsh.publicKey("auctioneer"),
+])
+
+export function closeOrderHistory(
+  args: CloseOrderHistoryArgs,
+  accounts: CloseOrderHistoryAccounts
+) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: false },
+    { pubkey: accounts.orderHistory, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([19, 215, 126, 240, 10, 36, 238, 54])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      auctionId: args.auctionId,
+      auctioneer: args.auctioneer,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
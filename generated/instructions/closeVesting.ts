@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseVestingArgs {
+  auctionId: Array<number>
+  auctioneer: PublicKey
+}
+
+export interface CloseVestingAccounts {
+  user: PublicKey
+  openOrders: PublicKey
+  vesting: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 10, "auctionId"),
+  borsh.publicKey("auctioneer"),
+])
+
+export function closeVesting(
+  args: CloseVestingArgs,
+  accounts: CloseVestingAccounts
+) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: false },
+    { pubkey: accounts.vesting, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([231, 75, 231, 133, 93, 227, 250, 252])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      auctionId: args.auctionId,
+      auctioneer: args.auctioneer,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,29 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DepositFixedSupplyAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+  baseMint: PublicKey
+  auctioneerBase: PublicKey
+  baseVault: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export function depositFixedSupply(accounts: DepositFixedSupplyAccounts) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.auctioneerBase, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([222, 197, 226, 174, 200, 234, 187, 13])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -3,17 +3,34 @@ export {
   InitAuctionArgs,
   InitAuctionAccounts,
 } from "./initAuction"
+export {
+  updateAuction,
+  UpdateAuctionArgs,
+  UpdateAuctionAccounts,
+} from "./updateAuction"
+export {
+  depositFixedSupply,
+  DepositFixedSupplyAccounts,
+} from "./depositFixedSupply"
 export {
   initOpenOrders,
   InitOpenOrdersArgs,
   InitOpenOrdersAccounts,
 } from "./initOpenOrders"
 export { newOrder, NewOrderArgs, NewOrderAccounts } from "./newOrder"
+export {
+  newQuoteBudgetOrder,
+  NewQuoteBudgetOrderArgs,
+  NewQuoteBudgetOrderAccounts,
+} from "./newQuoteBudgetOrder"
+export { newOrders, NewOrdersArgs, NewOrdersAccounts } from "./newOrders"
 export {
   cancelOrder,
   CancelOrderArgs,
   CancelOrderAccounts,
 } from "./cancelOrder"
+export { cancelAllOrders, CancelAllOrdersAccounts } from "./cancelAllOrders"
+export { amendOrder, AmendOrderArgs, AmendOrderAccounts } from "./amendOrder"
 export {
   newEncryptedOrder,
   NewEncryptedOrderArgs,
@@ -24,11 +41,31 @@ export {
   CancelEncryptedOrderArgs,
   CancelEncryptedOrderAccounts,
 } from "./cancelEncryptedOrder"
+export {
+  submitKeyShare,
+  SubmitKeyShareArgs,
+  SubmitKeyShareAccounts,
+} from "./submitKeyShare"
 export {
   decryptOrder,
   DecryptOrderArgs,
   DecryptOrderAccounts,
 } from "./decryptOrder"
+export {
+  revealOwnOrders,
+  RevealOwnOrdersArgs,
+  RevealOwnOrdersAccounts,
+} from "./revealOwnOrders"
+export {
+  revealOrder,
+  RevealOrderArgs,
+  RevealOrderAccounts,
+} from "./revealOrder"
+export {
+  revealReservePrice,
+  RevealReservePriceArgs,
+  RevealReservePriceAccounts,
+} from "./revealReservePrice"
 export {
   calculateClearingPrice,
   CalculateClearingPriceArgs,
@@ -48,4 +85,23 @@ export {
   settleAndCloseOpenOrders,
   SettleAndCloseOpenOrdersAccounts,
 } from "./settleAndCloseOpenOrders"
+export {
+  settleOpenOrdersBatch,
+  SettleOpenOrdersBatchAccounts,
+} from "./settleOpenOrdersBatch"
+export { claimVested, ClaimVestedAccounts } from "./claimVested"
+export { abortAuction, AbortAuctionAccounts } from "./abortAuction"
+export { withdrawProceeds, WithdrawProceedsAccounts } from "./withdrawProceeds"
+export { sweepDust, SweepDustAccounts } from "./sweepDust"
 export { closeAobAccounts, CloseAobAccountsAccounts } from "./closeAobAccounts"
+export { closeAuction, CloseAuctionAccounts } from "./closeAuction"
+export {
+  closeOrderHistory,
+  CloseOrderHistoryArgs,
+  CloseOrderHistoryAccounts,
+} from "./closeOrderHistory"
+export {
+  closeVesting,
+  CloseVestingArgs,
+  CloseVestingAccounts,
+} from "./closeVesting"
@@ -18,8 +18,8 @@ export interface InitAuctionAccounts {
   baseMint: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
-  rent: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
   systemProgram: PublicKey
 }
 
@@ -39,8 +39,8 @@ export function initAuction(
     { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.rent, isSigner: false, isWritable: false },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([73, 108, 200, 53, 221, 115, 20, 41])
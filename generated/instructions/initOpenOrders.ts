@@ -7,6 +7,8 @@ import { PROGRAM_ID } from "../programId"
 export interface InitOpenOrdersArgs {
   side: types.SideKind
   maxOrders: number
+  allowlistProof: Array<Array<number>>
+  maxBaseAllocation: BN
 }
 
 export interface InitOpenOrdersAccounts {
@@ -14,8 +16,7 @@ export interface InitOpenOrdersAccounts {
   auction: PublicKey
   openOrders: PublicKey
   orderHistory: PublicKey
-  quoteMint: PublicKey
-  baseMint: PublicKey
+  vesting: PublicKey
   userQuote: PublicKey
   userBase: PublicKey
   systemProgram: PublicKey
@@ -24,6 +25,8 @@ export interface InitOpenOrdersAccounts {
 export const layout = borsh.struct([
   types.Side.layout("side"),
   borsh.u8("maxOrders"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "allowlistProof"),
+  borsh.u64("maxBaseAllocation"),
 ])
 
 export function initOpenOrders(
@@ -32,11 +35,10 @@ export function initOpenOrders(
 ) {
   const keys = [
     { pubkey: accounts.user, isSigner: true, isWritable: true },
-    { pubkey: accounts.auction, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
     { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
     { pubkey: accounts.orderHistory, isSigner: false, isWritable: true },
-    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
-    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.vesting, isSigner: false, isWritable: true },
     { pubkey: accounts.userQuote, isSigner: false, isWritable: false },
     { pubkey: accounts.userBase, isSigner: false, isWritable: false },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
@@ -47,6 +49,8 @@ export function initOpenOrders(
     {
       side: args.side.toEncodable(),
       maxOrders: args.maxOrders,
+      allowlistProof: args.allowlistProof,
+      maxBaseAllocation: args.maxBaseAllocation,
     },
     buffer
   )
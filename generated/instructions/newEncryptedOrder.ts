@@ -21,7 +21,8 @@ export interface NewEncryptedOrderAccounts {
   userBase: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
 }
 
 export const layout = borsh.struct([
@@ -45,7 +46,8 @@ export function newEncryptedOrder(
     { pubkey: accounts.userBase, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([152, 240, 109, 44, 124, 106, 29, 65])
   const buffer = Buffer.alloc(1000)
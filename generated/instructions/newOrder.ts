@@ -22,7 +22,8 @@ export interface NewOrderAccounts {
   userBase: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
 }
 
 export const layout = borsh.struct([
@@ -33,7 +34,7 @@ export const layout = borsh.struct([
 export function newOrder(args: NewOrderArgs, accounts: NewOrderAccounts) {
   const keys = [
     { pubkey: accounts.user, isSigner: true, isWritable: false },
-    { pubkey: accounts.auction, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
     { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
     { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
     { pubkey: accounts.bids, isSigner: false, isWritable: true },
@@ -44,7 +45,8 @@ export function newOrder(args: NewOrderArgs, accounts: NewOrderAccounts) {
     { pubkey: accounts.userBase, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([153, 0, 116, 34, 241, 46, 40, 139])
   const buffer = Buffer.alloc(1000)
@@ -0,0 +1,66 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NewQuoteBudgetOrderArgs {
+  limitPrice: BN
+  maxQuoteQty: BN
+}
+
+export interface NewQuoteBudgetOrderAccounts {
+  user: PublicKey
+  auction: PublicKey
+  openOrders: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+  quoteMint: PublicKey
+  baseMint: PublicKey
+  userQuote: PublicKey
+  userBase: PublicKey
+  quoteVault: PublicKey
+  baseVault: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("limitPrice"),
+  borsh.u64("maxQuoteQty"),
+])
+
+export function newQuoteBudgetOrder(
+  args: NewQuoteBudgetOrderArgs,
+  accounts: NewQuoteBudgetOrderAccounts
+) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.bids, isSigner: false, isWritable: true },
+    { pubkey: accounts.asks, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.userQuote, isSigner: false, isWritable: true },
+    { pubkey: accounts.userBase, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([38, 235, 132, 53, 231, 55, 21, 230])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      limitPrice: args.limitPrice,
+      maxQuoteQty: args.maxQuoteQty,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,53 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevealOrderArgs {
+  orderIdx: number
+  priceAndQuantity: Array<number>
+  salt: Array<number>
+}
+
+export interface RevealOrderAccounts {
+  user: PublicKey
+  auction: PublicKey
+  openOrders: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u8("orderIdx"),
+  borsh.vecU8("priceAndQuantity"),
+  borsh.array(borsh.u8(), 32, "salt"),
+])
+
+export function revealOrder(
+  args: RevealOrderArgs,
+  accounts: RevealOrderAccounts
+) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.bids, isSigner: false, isWritable: true },
+    { pubkey: accounts.asks, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([25, 244, 42, 219, 17, 156, 211, 74])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      orderIdx: args.orderIdx,
+      priceAndQuantity: Buffer.from(args.priceAndQuantity),
+      salt: args.salt,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
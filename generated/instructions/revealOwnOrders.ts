@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevealOwnOrdersArgs {
+  secretKey: Array<number>
+}
+
+export interface RevealOwnOrdersAccounts {
+  user: PublicKey
+  auction: PublicKey
+  openOrders: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+}
+
+export const layout = borsh.struct([borsh.array(borsh.u8(), 32, "secretKey")])
+
+export function revealOwnOrders(
+  args: RevealOwnOrdersArgs,
+  accounts: RevealOwnOrdersAccounts
+) {
+  const keys = [
+    { pubkey: accounts.user, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+    { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: true },
+    { pubkey: accounts.bids, isSigner: false, isWritable: true },
+    { pubkey: accounts.asks, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([41, 109, 226, 238, 196, 70, 40, 109])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      secretKey: args.secretKey,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
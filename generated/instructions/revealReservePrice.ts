@@ -0,0 +1,42 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevealReservePriceArgs {
+  reservePrice: BN
+  salt: Array<number>
+}
+
+export interface RevealReservePriceAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("reservePrice"),
+  borsh.array(borsh.u8(), 32, "salt"),
+])
+
+export function revealReservePrice(
+  args: RevealReservePriceArgs,
+  accounts: RevealReservePriceAccounts
+) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([55, 186, 240, 84, 138, 64, 53, 25])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      reservePrice: args.reservePrice,
+      salt: args.salt,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -9,6 +9,7 @@ export interface SettleAndCloseOpenOrdersAccounts {
   auction: PublicKey
   openOrders: PublicKey
   orderHistory: PublicKey
+  vesting: PublicKey
   quoteVault: PublicKey
   baseVault: PublicKey
   quoteMint: PublicKey
@@ -16,7 +17,8 @@ export interface SettleAndCloseOpenOrdersAccounts {
   userQuote: PublicKey
   userBase: PublicKey
   systemProgram: PublicKey
-  tokenProgram: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
 }
 
 export function settleAndCloseOpenOrders(
@@ -24,9 +26,10 @@ export function settleAndCloseOpenOrders(
 ) {
   const keys = [
     { pubkey: accounts.user, isSigner: false, isWritable: true },
-    { pubkey: accounts.auction, isSigner: false, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
     { pubkey: accounts.openOrders, isSigner: false, isWritable: true },
     { pubkey: accounts.orderHistory, isSigner: false, isWritable: true },
+    { pubkey: accounts.vesting, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
     { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
     { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
@@ -34,7 +37,8 @@ export function settleAndCloseOpenOrders(
     { pubkey: accounts.userQuote, isSigner: false, isWritable: true },
     { pubkey: accounts.userBase, isSigner: false, isWritable: true },
     { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
-    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
   ]
   const identifier = Buffer.from([224, 158, 68, 49, 222, 197, 3, 235])
   const data = identifier
@@ -0,0 +1,37 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SubmitKeyShareArgs {
+  share: Array<number>
+}
+
+export interface SubmitKeyShareAccounts {
+  member: PublicKey
+  auction: PublicKey
+}
+
+export const layout = borsh.struct([borsh.array(borsh.u8(), 32, "share")])
+
+export function submitKeyShare(
+  args: SubmitKeyShareArgs,
+  accounts: SubmitKeyShareAccounts
+) {
+  const keys = [
+    { pubkey: accounts.member, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([148, 48, 213, 39, 104, 17, 188, 198])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      share: args.share,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,43 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SweepDustAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+  eventQueue: PublicKey
+  bids: PublicKey
+  asks: PublicKey
+  quoteVault: PublicKey
+  baseVault: PublicKey
+  quoteMint: PublicKey
+  baseMint: PublicKey
+  auctioneerQuote: PublicKey
+  auctioneerBase: PublicKey
+  quoteTokenProgram: PublicKey
+  baseTokenProgram: PublicKey
+}
+
+export function sweepDust(accounts: SweepDustAccounts) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventQueue, isSigner: false, isWritable: false },
+    { pubkey: accounts.bids, isSigner: false, isWritable: false },
+    { pubkey: accounts.asks, isSigner: false, isWritable: false },
+    { pubkey: accounts.quoteVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.baseVault, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseMint, isSigner: false, isWritable: false },
+    { pubkey: accounts.auctioneerQuote, isSigner: false, isWritable: true },
+    { pubkey: accounts.auctioneerBase, isSigner: false, isWritable: true },
+    { pubkey: accounts.quoteTokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.baseTokenProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([9, 49, 242, 88, 156, 84, 109, 15])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
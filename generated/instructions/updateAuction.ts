@@ -0,0 +1,37 @@
+import { TransactionInstruction, PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UpdateAuctionArgs {
+  args: types.InitAuctionArgsFields
+}
+
+export interface UpdateAuctionAccounts {
+  auctioneer: PublicKey
+  auction: PublicKey
+}
+
+export const layout = borsh.struct([types.InitAuctionArgs.layout("args")])
+
+export function updateAuction(
+  args: UpdateAuctionArgs,
+  accounts: UpdateAuctionAccounts
+) {
+  const keys = [
+    { pubkey: accounts.auctioneer, isSigner: true, isWritable: false },
+    { pubkey: accounts.auction, isSigner: false, isWritable: true },
+  ]
+  const identifier = Buffer.from([8, 87, 207, 233, 254, 160, 120, 224])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      args: types.InitAuctionArgs.toEncodable(args.args),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId: PROGRAM_ID, data })
+  return ix
+}
@@ -0,0 +1,86 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface PriceTimeJSON {
+  kind: "PriceTime"
+}
+
+export class PriceTime {
+  readonly discriminator = 0
+  readonly kind = "PriceTime"
+
+  toJSON(): PriceTimeJSON {
+    return {
+      kind: "PriceTime",
+    }
+  }
+
+  toEncodable() {
+    return {
+      PriceTime: {},
+    }
+  }
+}
+
+export interface ProRataJSON {
+  kind: "ProRata"
+}
+
+export class ProRata {
+  readonly discriminator = 1
+  readonly kind = "ProRata"
+
+  toJSON(): ProRataJSON {
+    return {
+      kind: "ProRata",
+    }
+  }
+
+  toEncodable() {
+    return {
+      ProRata: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.FillAllocationTypesKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("PriceTime" in obj) {
+    return new PriceTime()
+  }
+  if ("ProRata" in obj) {
+    return new ProRata()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(
+  obj: types.FillAllocationTypesJSON
+): types.FillAllocationTypesKind {
+  switch (obj.kind) {
+    case "PriceTime": {
+      return new PriceTime()
+    }
+    case "ProRata": {
+      return new ProRata()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "PriceTime"),
+    borsh.struct([], "ProRata"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
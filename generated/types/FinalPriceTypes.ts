@@ -3,23 +3,44 @@ import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
 import * as borsh from "@project-serum/borsh"
 
-export interface BestBidJSON {
-  kind: "BestBid"
+export interface LowestWinningBidJSON {
+  kind: "LowestWinningBid"
 }
 
-export class BestBid {
+export class LowestWinningBid {
   readonly discriminator = 0
-  readonly kind = "BestBid"
+  readonly kind = "LowestWinningBid"
 
-  toJSON(): BestBidJSON {
+  toJSON(): LowestWinningBidJSON {
     return {
-      kind: "BestBid",
+      kind: "LowestWinningBid",
     }
   }
 
   toEncodable() {
     return {
-      BestBid: {},
+      LowestWinningBid: {},
+    }
+  }
+}
+
+export interface HighestWinningAskJSON {
+  kind: "HighestWinningAsk"
+}
+
+export class HighestWinningAsk {
+  readonly discriminator = 1
+  readonly kind = "HighestWinningAsk"
+
+  toJSON(): HighestWinningAskJSON {
+    return {
+      kind: "HighestWinningAsk",
+    }
+  }
+
+  toEncodable() {
+    return {
+      HighestWinningAsk: {},
     }
   }
 }
@@ -29,7 +50,7 @@ export interface MidpointJSON {
 }
 
 export class Midpoint {
-  readonly discriminator = 1
+  readonly discriminator = 2
   readonly kind = "Midpoint"
 
   toJSON(): MidpointJSON {
@@ -45,18 +66,45 @@ export class Midpoint {
   }
 }
 
+export interface FirstLosingBidJSON {
+  kind: "FirstLosingBid"
+}
+
+export class FirstLosingBid {
+  readonly discriminator = 3
+  readonly kind = "FirstLosingBid"
+
+  toJSON(): FirstLosingBidJSON {
+    return {
+      kind: "FirstLosingBid",
+    }
+  }
+
+  toEncodable() {
+    return {
+      FirstLosingBid: {},
+    }
+  }
+}
+
 // eslint-disable-next-line @typescript-eslint/no-explicit-any
 export function fromDecoded(obj: any): types.FinalPriceTypesKind {
   if (typeof obj !== "object") {
     throw new Error("Invalid enum object")
   }
 
-  if ("BestBid" in obj) {
-    return new BestBid()
+  if ("LowestWinningBid" in obj) {
+    return new LowestWinningBid()
+  }
+  if ("HighestWinningAsk" in obj) {
+    return new HighestWinningAsk()
   }
   if ("Midpoint" in obj) {
     return new Midpoint()
   }
+  if ("FirstLosingBid" in obj) {
+    return new FirstLosingBid()
+  }
 
   throw new Error("Invalid enum object")
 }
@@ -65,19 +113,27 @@ export function fromJSON(
   obj: types.FinalPriceTypesJSON
 ): types.FinalPriceTypesKind {
   switch (obj.kind) {
-    case "BestBid": {
-      return new BestBid()
+    case "LowestWinningBid": {
+      return new LowestWinningBid()
+    }
+    case "HighestWinningAsk": {
+      return new HighestWinningAsk()
     }
     case "Midpoint": {
       return new Midpoint()
     }
+    case "FirstLosingBid": {
+      return new FirstLosingBid()
+    }
   }
 }
 
 export function layout(property?: string) {
   const ret = borsh.rustEnum([
-    borsh.struct([], "BestBid"),
+    borsh.struct([], "LowestWinningBid"),
+    borsh.struct([], "HighestWinningAsk"),
     borsh.struct([], "Midpoint"),
+    borsh.struct([], "FirstLosingBid"),
   ])
   if (property !== undefined) {
     return ret.replicate(property)
@@ -8,11 +8,33 @@ export interface InitAuctionArgsFields {
   startOrderPhase: BN
   endOrderPhase: BN
   endDecryptionPhase: BN
+  softCloseWindow: BN
+  softCloseExtension: BN
+  maxSoftCloseExtension: BN
   areAsksEncrypted: boolean
   areBidsEncrypted: boolean
   naclPubkey: Array<number>
+  isCommitReveal: boolean
+  unrevealedPenaltyBps: number
+  committee: Array<PublicKey>
+  committeeThreshold: number
+  committeePublicShares: Array<Array<number>>
+  finalPriceType: types.FinalPriceTypesKind
+  fillAllocationType: types.FillAllocationTypesKind
+  isPayAsBid: boolean
+  baseLotSize: BN
+  quoteLotSize: BN
   minBaseOrderSize: BN
   tickSize: BN
+  maxBasePerUser: BN
+  allowlistRoot: Array<number>
+  reservePrice: BN
+  reservePriceCommitment: Array<number>
+  minBaseMatched: BN
+  minQuoteRaised: BN
+  fixedBaseSupply: BN
+  vestingCliff: BN
+  vestingDuration: BN
 }
 
 export interface InitAuctionArgsJSON {
@@ -20,11 +42,33 @@ export interface InitAuctionArgsJSON {
   startOrderPhase: string
   endOrderPhase: string
   endDecryptionPhase: string
+  softCloseWindow: string
+  softCloseExtension: string
+  maxSoftCloseExtension: string
   areAsksEncrypted: boolean
   areBidsEncrypted: boolean
   naclPubkey: Array<number>
+  isCommitReveal: boolean
+  unrevealedPenaltyBps: number
+  committee: Array<string>
+  committeeThreshold: number
+  committeePublicShares: Array<Array<number>>
+  finalPriceType: types.FinalPriceTypesJSON
+  fillAllocationType: types.FillAllocationTypesJSON
+  isPayAsBid: boolean
+  baseLotSize: string
+  quoteLotSize: string
   minBaseOrderSize: string
   tickSize: string
+  maxBasePerUser: string
+  allowlistRoot: Array<number>
+  reservePrice: string
+  reservePriceCommitment: Array<number>
+  minBaseMatched: string
+  minQuoteRaised: string
+  fixedBaseSupply: string
+  vestingCliff: string
+  vestingDuration: string
 }
 
 export class InitAuctionArgs {
@@ -32,22 +76,66 @@ export class InitAuctionArgs {
   readonly startOrderPhase: BN
   readonly endOrderPhase: BN
   readonly endDecryptionPhase: BN
+  readonly softCloseWindow: BN
+  readonly softCloseExtension: BN
+  readonly maxSoftCloseExtension: BN
   readonly areAsksEncrypted: boolean
   readonly areBidsEncrypted: boolean
   readonly naclPubkey: Array<number>
+  readonly isCommitReveal: boolean
+  readonly unrevealedPenaltyBps: number
+  readonly committee: Array<PublicKey>
+  readonly committeeThreshold: number
+  readonly committeePublicShares: Array<Array<number>>
+  readonly finalPriceType: types.FinalPriceTypesKind
+  readonly fillAllocationType: types.FillAllocationTypesKind
+  readonly isPayAsBid: boolean
+  readonly baseLotSize: BN
+  readonly quoteLotSize: BN
   readonly minBaseOrderSize: BN
   readonly tickSize: BN
+  readonly maxBasePerUser: BN
+  readonly allowlistRoot: Array<number>
+  readonly reservePrice: BN
+  readonly reservePriceCommitment: Array<number>
+  readonly minBaseMatched: BN
+  readonly minQuoteRaised: BN
+  readonly fixedBaseSupply: BN
+  readonly vestingCliff: BN
+  readonly vestingDuration: BN
 
   constructor(fields: InitAuctionArgsFields) {
     this.auctionId = fields.auctionId
     this.startOrderPhase = fields.startOrderPhase
     this.endOrderPhase = fields.endOrderPhase
     this.endDecryptionPhase = fields.endDecryptionPhase
+    this.softCloseWindow = fields.softCloseWindow
+    this.softCloseExtension = fields.softCloseExtension
+    this.maxSoftCloseExtension = fields.maxSoftCloseExtension
     this.areAsksEncrypted = fields.areAsksEncrypted
     this.areBidsEncrypted = fields.areBidsEncrypted
     this.naclPubkey = fields.naclPubkey
+    this.isCommitReveal = fields.isCommitReveal
+    this.unrevealedPenaltyBps = fields.unrevealedPenaltyBps
+    this.committee = fields.committee
+    this.committeeThreshold = fields.committeeThreshold
+    this.committeePublicShares = fields.committeePublicShares
+    this.finalPriceType = fields.finalPriceType
+    this.fillAllocationType = fields.fillAllocationType
+    this.isPayAsBid = fields.isPayAsBid
+    this.baseLotSize = fields.baseLotSize
+    this.quoteLotSize = fields.quoteLotSize
     this.minBaseOrderSize = fields.minBaseOrderSize
     this.tickSize = fields.tickSize
+    this.maxBasePerUser = fields.maxBasePerUser
+    this.allowlistRoot = fields.allowlistRoot
+    this.reservePrice = fields.reservePrice
+    this.reservePriceCommitment = fields.reservePriceCommitment
+    this.minBaseMatched = fields.minBaseMatched
+    this.minQuoteRaised = fields.minQuoteRaised
+    this.fixedBaseSupply = fields.fixedBaseSupply
+    this.vestingCliff = fields.vestingCliff
+    this.vestingDuration = fields.vestingDuration
   }
 
   static layout(property?: string) {
@@ -57,11 +145,33 @@ export class InitAuctionArgs {
         borsh.i64("startOrderPhase"),
         borsh.i64("endOrderPhase"),
         borsh.i64("endDecryptionPhase"),
+        borsh.i64("softCloseWindow"),
+        borsh.i64("softCloseExtension"),
+        borsh.i64("maxSoftCloseExtension"),
         borsh.bool("areAsksEncrypted"),
         borsh.bool("areBidsEncrypted"),
         borsh.vecU8("naclPubkey"),
+        borsh.bool("isCommitReveal"),
+        borsh.u16("unrevealedPenaltyBps"),
+        borsh.vec(borsh.publicKey(), "committee"),
+        borsh.u8("committeeThreshold"),
+        borsh.vec(borsh.array(borsh.u8(), 32), "committeePublicShares"),
+        types.FinalPriceTypes.layout("finalPriceType"),
+        types.FillAllocationTypes.layout("fillAllocationType"),
+        borsh.bool("isPayAsBid"),
+        borsh.u64("baseLotSize"),
+        borsh.u64("quoteLotSize"),
         borsh.u64("minBaseOrderSize"),
         borsh.u64("tickSize"),
+        borsh.u64("maxBasePerUser"),
+        borsh.array(borsh.u8(), 32, "allowlistRoot"),
+        borsh.u64("reservePrice"),
+        borsh.array(borsh.u8(), 32, "reservePriceCommitment"),
+        borsh.u64("minBaseMatched"),
+        borsh.u64("minQuoteRaised"),
+        borsh.u64("fixedBaseSupply"),
+        borsh.i64("vestingCliff"),
+        borsh.i64("vestingDuration"),
       ],
       property
     )
@@ -74,11 +184,35 @@ export class InitAuctionArgs {
       startOrderPhase: obj.startOrderPhase,
       endOrderPhase: obj.endOrderPhase,
       endDecryptionPhase: obj.endDecryptionPhase,
+      softCloseWindow: obj.softCloseWindow,
+      softCloseExtension: obj.softCloseExtension,
+      maxSoftCloseExtension: obj.maxSoftCloseExtension,
       areAsksEncrypted: obj.areAsksEncrypted,
       areBidsEncrypted: obj.areBidsEncrypted,
       naclPubkey: Array.from(obj.naclPubkey),
+      isCommitReveal: obj.isCommitReveal,
+      unrevealedPenaltyBps: obj.unrevealedPenaltyBps,
+      committee: obj.committee,
+      committeeThreshold: obj.committeeThreshold,
+      committeePublicShares: obj.committeePublicShares,
+      finalPriceType: types.FinalPriceTypes.fromDecoded(obj.finalPriceType),
+      fillAllocationType: types.FillAllocationTypes.fromDecoded(
+        obj.fillAllocationType
+      ),
+      isPayAsBid: obj.isPayAsBid,
+      baseLotSize: obj.baseLotSize,
+      quoteLotSize: obj.quoteLotSize,
       minBaseOrderSize: obj.minBaseOrderSize,
       tickSize: obj.tickSize,
+      maxBasePerUser: obj.maxBasePerUser,
+      allowlistRoot: obj.allowlistRoot,
+      reservePrice: obj.reservePrice,
+      reservePriceCommitment: obj.reservePriceCommitment,
+      minBaseMatched: obj.minBaseMatched,
+      minQuoteRaised: obj.minQuoteRaised,
+      fixedBaseSupply: obj.fixedBaseSupply,
+      vestingCliff: obj.vestingCliff,
+      vestingDuration: obj.vestingDuration,
     })
   }
 
@@ -88,11 +222,33 @@ export class InitAuctionArgs {
       startOrderPhase: fields.startOrderPhase,
       endOrderPhase: fields.endOrderPhase,
       endDecryptionPhase: fields.endDecryptionPhase,
+      softCloseWindow: fields.softCloseWindow,
+      softCloseExtension: fields.softCloseExtension,
+      maxSoftCloseExtension: fields.maxSoftCloseExtension,
       areAsksEncrypted: fields.areAsksEncrypted,
       areBidsEncrypted: fields.areBidsEncrypted,
       naclPubkey: Buffer.from(fields.naclPubkey),
+      isCommitReveal: fields.isCommitReveal,
+      unrevealedPenaltyBps: fields.unrevealedPenaltyBps,
+      committee: fields.committee,
+      committeeThreshold: fields.committeeThreshold,
+      committeePublicShares: fields.committeePublicShares,
+      finalPriceType: fields.finalPriceType.toEncodable(),
+      fillAllocationType: fields.fillAllocationType.toEncodable(),
+      isPayAsBid: fields.isPayAsBid,
+      baseLotSize: fields.baseLotSize,
+      quoteLotSize: fields.quoteLotSize,
       minBaseOrderSize: fields.minBaseOrderSize,
       tickSize: fields.tickSize,
+      maxBasePerUser: fields.maxBasePerUser,
+      allowlistRoot: fields.allowlistRoot,
+      reservePrice: fields.reservePrice,
+      reservePriceCommitment: fields.reservePriceCommitment,
+      minBaseMatched: fields.minBaseMatched,
+      minQuoteRaised: fields.minQuoteRaised,
+      fixedBaseSupply: fields.fixedBaseSupply,
+      vestingCliff: fields.vestingCliff,
+      vestingDuration: fields.vestingDuration,
     }
   }
 
@@ -102,11 +258,33 @@ export class InitAuctionArgs {
       startOrderPhase: this.startOrderPhase.toString(),
       endOrderPhase: this.endOrderPhase.toString(),
       endDecryptionPhase: this.endDecryptionPhase.toString(),
+      softCloseWindow: this.softCloseWindow.toString(),
+      softCloseExtension: this.softCloseExtension.toString(),
+      maxSoftCloseExtension: this.maxSoftCloseExtension.toString(),
       areAsksEncrypted: this.areAsksEncrypted,
       areBidsEncrypted: this.areBidsEncrypted,
       naclPubkey: this.naclPubkey,
+      isCommitReveal: this.isCommitReveal,
+      unrevealedPenaltyBps: this.unrevealedPenaltyBps,
+      committee: this.committee.map((item) => item.toString()),
+      committeeThreshold: this.committeeThreshold,
+      committeePublicShares: this.committeePublicShares,
+      finalPriceType: this.finalPriceType.toJSON(),
+      fillAllocationType: this.fillAllocationType.toJSON(),
+      isPayAsBid: this.isPayAsBid,
+      baseLotSize: this.baseLotSize.toString(),
+      quoteLotSize: this.quoteLotSize.toString(),
       minBaseOrderSize: this.minBaseOrderSize.toString(),
       tickSize: this.tickSize.toString(),
+      maxBasePerUser: this.maxBasePerUser.toString(),
+      allowlistRoot: this.allowlistRoot,
+      reservePrice: this.reservePrice.toString(),
+      reservePriceCommitment: this.reservePriceCommitment,
+      minBaseMatched: this.minBaseMatched.toString(),
+      minQuoteRaised: this.minQuoteRaised.toString(),
+      fixedBaseSupply: this.fixedBaseSupply.toString(),
+      vestingCliff: this.vestingCliff.toString(),
+      vestingDuration: this.vestingDuration.toString(),
     }
   }
 
@@ -116,11 +294,35 @@ export class InitAuctionArgs {
       startOrderPhase: new BN(obj.startOrderPhase),
       endOrderPhase: new BN(obj.endOrderPhase),
       endDecryptionPhase: new BN(obj.endDecryptionPhase),
+      softCloseWindow: new BN(obj.softCloseWindow),
+      softCloseExtension: new BN(obj.softCloseExtension),
+      maxSoftCloseExtension: new BN(obj.maxSoftCloseExtension),
       areAsksEncrypted: obj.areAsksEncrypted,
       areBidsEncrypted: obj.areBidsEncrypted,
       naclPubkey: obj.naclPubkey,
+      isCommitReveal: obj.isCommitReveal,
+      unrevealedPenaltyBps: obj.unrevealedPenaltyBps,
+      committee: obj.committee.map((item) => new PublicKey(item)),
+      committeeThreshold: obj.committeeThreshold,
+      committeePublicShares: obj.committeePublicShares,
+      finalPriceType: types.FinalPriceTypes.fromJSON(obj.finalPriceType),
+      fillAllocationType: types.FillAllocationTypes.fromJSON(
+        obj.fillAllocationType
+      ),
+      isPayAsBid: obj.isPayAsBid,
+      baseLotSize: new BN(obj.baseLotSize),
+      quoteLotSize: new BN(obj.quoteLotSize),
       minBaseOrderSize: new BN(obj.minBaseOrderSize),
       tickSize: new BN(obj.tickSize),
+      maxBasePerUser: new BN(obj.maxBasePerUser),
+      allowlistRoot: obj.allowlistRoot,
+      reservePrice: new BN(obj.reservePrice),
+      reservePriceCommitment: obj.reservePriceCommitment,
+      minBaseMatched: new BN(obj.minBaseMatched),
+      minQuoteRaised: new BN(obj.minQuoteRaised),
+      fixedBaseSupply: new BN(obj.fixedBaseSupply),
+      vestingCliff: new BN(obj.vestingCliff),
+      vestingDuration: new BN(obj.vestingDuration),
     })
   }
 
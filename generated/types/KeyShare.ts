@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface KeyShareFields {
+  memberIdx: number
+  share: Array<number>
+}
+
+export interface KeyShareJSON {
+  memberIdx: number
+  share: Array<number>
+}
+
+export class KeyShare {
+  readonly memberIdx: number
+  readonly share: Array<number>
+
+  constructor(fields: KeyShareFields) {
+    this.memberIdx = fields.memberIdx
+    this.share = fields.share
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u8("memberIdx"), borsh.array(borsh.u8(), 32, "share")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new KeyShare({
+      memberIdx: obj.memberIdx,
+      share: obj.share,
+    })
+  }
+
+  static toEncodable(fields: KeyShareFields) {
+    return {
+      memberIdx: fields.memberIdx,
+      share: fields.share,
+    }
+  }
+
+  toJSON(): KeyShareJSON {
+    return {
+      memberIdx: this.memberIdx,
+      share: this.share,
+    }
+  }
+
+  static fromJSON(obj: KeyShareJSON): KeyShare {
+    return new KeyShare({
+      memberIdx: obj.memberIdx,
+      share: obj.share,
+    })
+  }
+
+  toEncodable() {
+    return KeyShare.toEncodable(this)
+  }
+}
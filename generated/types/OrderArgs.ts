@@ -0,0 +1,64 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface OrderArgsFields {
+  limitPrice: BN
+  maxBaseQty: BN
+}
+
+export interface OrderArgsJSON {
+  limitPrice: string
+  maxBaseQty: string
+}
+
+export class OrderArgs {
+  readonly limitPrice: BN
+  readonly maxBaseQty: BN
+
+  constructor(fields: OrderArgsFields) {
+    this.limitPrice = fields.limitPrice
+    this.maxBaseQty = fields.maxBaseQty
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [borsh.u64("limitPrice"), borsh.u64("maxBaseQty")],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new OrderArgs({
+      limitPrice: obj.limitPrice,
+      maxBaseQty: obj.maxBaseQty,
+    })
+  }
+
+  static toEncodable(fields: OrderArgsFields) {
+    return {
+      limitPrice: fields.limitPrice,
+      maxBaseQty: fields.maxBaseQty,
+    }
+  }
+
+  toJSON(): OrderArgsJSON {
+    return {
+      limitPrice: this.limitPrice.toString(),
+      maxBaseQty: this.maxBaseQty.toString(),
+    }
+  }
+
+  static fromJSON(obj: OrderArgsJSON): OrderArgs {
+    return new OrderArgs({
+      limitPrice: new BN(obj.limitPrice),
+      maxBaseQty: new BN(obj.maxBaseQty),
+    })
+  }
+
+  toEncodable() {
+    return OrderArgs.toEncodable(this)
+  }
+}
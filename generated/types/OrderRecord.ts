@@ -0,0 +1,112 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@project-serum/borsh"
+
+export interface OrderRecordFields {
+  orderId: BN
+  limitPrice: BN
+  baseQty: BN
+  baseFilled: BN
+  quoteFilled: BN
+  baseRefunded: BN
+  quoteRefunded: BN
+}
+
+export interface OrderRecordJSON {
+  orderId: string
+  limitPrice: string
+  baseQty: string
+  baseFilled: string
+  quoteFilled: string
+  baseRefunded: string
+  quoteRefunded: string
+}
+
+export class OrderRecord {
+  readonly orderId: BN
+  readonly limitPrice: BN
+  readonly baseQty: BN
+  readonly baseFilled: BN
+  readonly quoteFilled: BN
+  readonly baseRefunded: BN
+  readonly quoteRefunded: BN
+
+  constructor(fields: OrderRecordFields) {
+    this.orderId = fields.orderId
+    this.limitPrice = fields.limitPrice
+    this.baseQty = fields.baseQty
+    this.baseFilled = fields.baseFilled
+    this.quoteFilled = fields.quoteFilled
+    this.baseRefunded = fields.baseRefunded
+    this.quoteRefunded = fields.quoteRefunded
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u128("orderId"),
+        borsh.u64("limitPrice"),
+        borsh.u64("baseQty"),
+        borsh.u64("baseFilled"),
+        borsh.u64("quoteFilled"),
+        borsh.u64("baseRefunded"),
+        borsh.u64("quoteRefunded"),
+      ],
+      property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new OrderRecord({
+      orderId: obj.orderId,
+      limitPrice: obj.limitPrice,
+      baseQty: obj.baseQty,
+      baseFilled: obj.baseFilled,
+      quoteFilled: obj.quoteFilled,
+      baseRefunded: obj.baseRefunded,
+      quoteRefunded: obj.quoteRefunded,
+    })
+  }
+
+  static toEncodable(fields: OrderRecordFields) {
+    return {
+      orderId: fields.orderId,
+      limitPrice: fields.limitPrice,
+      baseQty: fields.baseQty,
+      baseFilled: fields.baseFilled,
+      quoteFilled: fields.quoteFilled,
+      baseRefunded: fields.baseRefunded,
+      quoteRefunded: fields.quoteRefunded,
+    }
+  }
+
+  toJSON(): OrderRecordJSON {
+    return {
+      orderId: this.orderId.toString(),
+      limitPrice: this.limitPrice.toString(),
+      baseQty: this.baseQty.toString(),
+      baseFilled: this.baseFilled.toString(),
+      quoteFilled: this.quoteFilled.toString(),
+      baseRefunded: this.baseRefunded.toString(),
+      quoteRefunded: this.quoteRefunded.toString(),
+    }
+  }
+
+  static fromJSON(obj: OrderRecordJSON): OrderRecord {
+    return new OrderRecord({
+      orderId: new BN(obj.orderId),
+      limitPrice: new BN(obj.limitPrice),
+      baseQty: new BN(obj.baseQty),
+      baseFilled: new BN(obj.baseFilled),
+      quoteFilled: new BN(obj.quoteFilled),
+      baseRefunded: new BN(obj.baseRefunded),
+      quoteRefunded: new BN(obj.quoteRefunded),
+    })
+  }
+
+  toEncodable() {
+    return OrderRecord.toEncodable(this)
+  }
+}
@@ -1,3 +1,5 @@
+import * as FillAllocationTypes from "./FillAllocationTypes"
+import * as FinalPriceTypes from "./FinalPriceTypes"
 import * as Side from "./Side"
 
 export { AobBumps, AobBumpsFields, AobBumpsJSON } from "./AobBumps"
@@ -6,11 +8,36 @@ export {
   EncryptedOrderFields,
   EncryptedOrderJSON,
 } from "./EncryptedOrder"
+export { FillAllocationTypes }
+
+export type FillAllocationTypesKind =
+  | FillAllocationTypes.PriceTime
+  | FillAllocationTypes.ProRata
+export type FillAllocationTypesJSON =
+  | FillAllocationTypes.PriceTimeJSON
+  | FillAllocationTypes.ProRataJSON
+
+export { FinalPriceTypes }
+
+export type FinalPriceTypesKind =
+  | FinalPriceTypes.LowestWinningBid
+  | FinalPriceTypes.HighestWinningAsk
+  | FinalPriceTypes.Midpoint
+  | FinalPriceTypes.FirstLosingBid
+export type FinalPriceTypesJSON =
+  | FinalPriceTypes.LowestWinningBidJSON
+  | FinalPriceTypes.HighestWinningAskJSON
+  | FinalPriceTypes.MidpointJSON
+  | FinalPriceTypes.FirstLosingBidJSON
+
 export {
   InitAuctionArgs,
   InitAuctionArgsFields,
   InitAuctionArgsJSON,
 } from "./InitAuctionArgs"
+export { KeyShare, KeyShareFields, KeyShareJSON } from "./KeyShare"
+export { OrderArgs, OrderArgsFields, OrderArgsJSON } from "./OrderArgs"
+export { OrderRecord, OrderRecordFields, OrderRecordJSON } from "./OrderRecord"
 export { Side }
 
 export type SideKind = Side.Bid | Side.Ask
@@ -1,5 +1,11 @@
+use std::convert::TryFrom;
+
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use bonfida_utils::fp_math::fp32_div;
 
+use crate::consts::*;
+use crate::encryption::{is_valid_public_share, public_shares_match_committee_key};
 use crate::error::CustomErrors;
 use crate::program_accounts::*;
 use crate::types::*;
@@ -7,6 +13,9 @@ use crate::types::*;
 use agnostic_orderbook::{orderbook::OrderBookState, state::EventQueueHeader};
 
 pub fn is_order_phase_active(clock: Clock, auction: &Auction) -> bool {
+    if auction.is_cancelled {
+        return false;
+    }
     if clock.unix_timestamp < auction.start_order_phase {
         // return Err(error!(CustomErrors::OrderPhaseHasNotStarted));
         return false;
@@ -19,6 +28,9 @@ pub fn is_order_phase_active(clock: Clock, auction: &Auction) -> bool {
 }
 
 pub fn is_decryption_phase_active(clock: Clock, auction: &Auction) -> bool {
+    if auction.is_cancelled {
+        return false;
+    }
     if clock.unix_timestamp < auction.end_order_phase {
         // return Err(error!(CustomErrors::DecryptionPhaseHasNotStarted));
         return false;
@@ -34,14 +46,15 @@ pub fn is_calc_clearing_price_phase_active(clock: Clock, auction: &Auction) -> b
     if clock.unix_timestamp < auction.end_decryption_phase {
         return false;
     }
-    if auction.has_found_clearing_price {
+    if auction.has_found_clearing_price || auction.is_cancelled {
         return false;
     }
     true
 }
 
 pub fn is_match_orders_phase_active(auction: &Auction, order_book: &OrderBookState) -> bool {
-    if !auction.has_found_clearing_price {
+    // Cancelled auctions have no fills left, so matching just refunds every order
+    if !auction.has_found_clearing_price && !auction.is_cancelled {
         return false;
     }
     if order_book.is_empty() {
@@ -55,7 +68,8 @@ pub fn is_auction_over(
     order_book: &OrderBookState,
     event_queue_header: &EventQueueHeader,
 ) -> bool {
-    if !auction.has_found_clearing_price {
+    // A cancelled auction is over once everyone has cancelled their orders
+    if !auction.has_found_clearing_price && !auction.is_cancelled {
         return false;
     }
     if !order_book.is_empty() {
@@ -67,6 +81,107 @@ pub fn is_auction_over(
     true
 }
 
+/// Checks shared by creating an auction and updating it before it starts
+pub fn validate_auction_args(clock: Clock, args: &InitAuctionArgs) -> Result<()> {
+    // Orders phase ends before it starts
+    if args.end_order_phase <= args.start_order_phase {
+        return Err(error!(CustomErrors::InvalidStartTimes));
+    }
+    // Orders phase should end in the future
+    if args.end_order_phase <= clock.unix_timestamp {
+        return Err(error!(CustomErrors::InvalidEndTimes));
+    }
+    // Decryption phase should end at or after the end of the order phase
+    if args.end_decryption_phase < args.end_order_phase {
+        return Err(error!(CustomErrors::InvalidDecryptionEndTime));
+    }
+    if args.soft_close_window < 0
+        || args.soft_close_extension < 0
+        || args.max_soft_close_extension < 0
+    {
+        return Err(error!(CustomErrors::InvalidSoftCloseParams));
+    }
+    if args.base_lot_size == 0 || args.quote_lot_size == 0 {
+        return Err(error!(CustomErrors::InvalidLotSize));
+    }
+    if args.min_base_order_size == 0 {
+        return Err(error!(CustomErrors::InvalidMinBaseOrderSize));
+    }
+    if args.tick_size == 0 {
+        return Err(error!(CustomErrors::InvalidTickSize));
+    }
+    if args.reserve_price % args.tick_size != 0 {
+        return Err(error!(CustomErrors::ReservePriceNotAMultipleOfTickSize));
+    }
+    if args.reserve_price > 0 && args.reserve_price_commitment != [0; 32] {
+        return Err(error!(CustomErrors::InvalidReservePrice));
+    }
+    if args.fixed_base_supply > 0 && args.are_asks_encrypted {
+        return Err(error!(CustomErrors::AskOrdersNotAllowed));
+    }
+    if args.vesting_cliff < 0
+        || args.vesting_duration < 0
+        || args.vesting_cliff > args.vesting_duration
+    {
+        return Err(error!(CustomErrors::InvalidVestingParams));
+    }
+    if (args.is_commit_reveal && !args.are_asks_encrypted && !args.are_bids_encrypted)
+        || args.unrevealed_penalty_bps > 10_000
+    {
+        return Err(error!(CustomErrors::InvalidCommitRevealParams));
+    }
+    let committee_size = args.committee.len();
+    if committee_size > MAX_COMMITTEE_SIZE
+        || (committee_size == 0 && args.committee_threshold != 0)
+        || (committee_size > 0
+            && (args.committee_threshold == 0
+                || args.committee_threshold as usize > committee_size
+                || args.is_commit_reveal
+                || args.nacl_pubkey.len() != 32))
+        || args
+            .committee
+            .iter()
+            .enumerate()
+            .any(|(idx, member)| args.committee[..idx].contains(member))
+        || args.committee_public_shares.len() != committee_size
+        || !args
+            .committee_public_shares
+            .iter()
+            .all(is_valid_public_share)
+        || (committee_size > 0
+            && !public_shares_match_committee_key(
+                &args.committee_public_shares,
+                args.committee_threshold as usize,
+                &args.nacl_pubkey,
+            ))
+    {
+        return Err(error!(CustomErrors::InvalidCommitteeParams));
+    }
+    Ok(())
+}
+
+/// Auctions with a committee can only be decrypted once enough members have
+/// submitted verified key shares to rebuild the combined secret key
+pub fn has_enough_key_shares(auction: &Auction) -> bool {
+    auction.committee.is_empty() || auction.committee_secret_key != [0; 32]
+}
+
+/// Bids with a quote budget buy as much base as the budget covers at their
+/// limit price
+pub fn base_qty_from_quote_budget(limit_price: u64, max_quote_qty: u64) -> Result<u64> {
+    fp32_div(max_quote_qty, limit_price).ok_or_else(|| error!(CustomErrors::NumericalOverflow))
+}
+
+/// Quote locked by a bid, cancelling or matching it unlocks exactly this much.
+/// Everything a bid locks or pays is rounded up while asks are paid rounded
+/// down, so the quote vault can always cover the asks.
+pub fn bid_quote_qty(base_qty: u64, limit_price: u64) -> Result<u64> {
+    let quote_qty_fp32 = (base_qty as u128) * (limit_price as u128);
+    // Round up to the next whole quote lot
+    let quote_qty = (quote_qty_fp32 + u32::MAX as u128) >> 32;
+    u64::try_from(quote_qty).map_err(|_| error!(CustomErrors::NumericalOverflow))
+}
+
 pub fn validate_price_and_qty(
     auction: &Auction,
     limit_price: u64,
@@ -100,9 +215,184 @@ pub fn encrypted_orders_only(auction: &Auction, open_orders: &OpenOrders) -> Res
     Ok(())
 }
 
+/// The most base a user's bids can add up to, from the auction's per user
+/// limit and their allowlist allocation, whichever is lower
+pub fn max_base_for_user(auction: &Auction, open_orders: &OpenOrders) -> Option<u64> {
+    [auction.max_base_per_user, open_orders.max_base_allocation]
+        .iter()
+        .filter(|limit| **limit > 0)
+        .min()
+        .copied()
+}
+
+/// Bids can't add up to more than the user's limit
+pub fn has_space_under_max_base_per_user(
+    auction: &Auction,
+    open_orders: &OpenOrders,
+    new_base_qty: u64,
+) -> Result<()> {
+    if open_orders.side == Side::Ask {
+        return Ok(());
+    }
+    let max_base = match max_base_for_user(auction, open_orders) {
+        Some(max_base) => max_base,
+        None => return Ok(()),
+    };
+    let total_base_qty = open_orders
+        .base_qty_ordered
+        .checked_add(new_base_qty)
+        .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?;
+    if total_base_qty > max_base {
+        return Err(error!(CustomErrors::MaxBasePerUserExceeded));
+    }
+    Ok(())
+}
+
+/// Leaves are the hash of the user's pubkey and their max base allocation,
+/// pairs of nodes are sorted before hashing so proofs don't need directions
+pub fn is_on_allowlist(
+    allowlist_root: &[u8; 32],
+    allowlist_proof: &[[u8; 32]],
+    user: &Pubkey,
+    max_base_allocation: u64,
+) -> bool {
+    let mut node = keccak::hashv(&[user.as_ref(), &max_base_allocation.to_le_bytes()]).0;
+    for proof_node in allowlist_proof.iter() {
+        node = if node <= *proof_node {
+            keccak::hashv(&[&node, proof_node]).0
+        } else {
+            keccak::hashv(&[proof_node, &node]).0
+        };
+    }
+    node == *allowlist_root
+}
+
+pub fn can_settle_open_orders(open_orders: &OpenOrders) -> Result<()> {
+    if open_orders.num_orders > 0 {
+        return Err(error!(CustomErrors::OpenOrdersHasOpenOrders));
+    }
+    // Refunds are exact, so once all orders are gone nothing should be locked
+    if open_orders.quote_token_locked > 0 || open_orders.base_token_locked > 0 {
+        return Err(error!(CustomErrors::OpenOrdersHasLockedTokens));
+    }
+    Ok(())
+}
+
 pub fn has_space_for_new_orders(open_orders: &OpenOrders) -> Result<()> {
     if open_orders.num_orders == open_orders.max_orders {
         return Err(error!(CustomErrors::TooManyOrders));
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(user: &Pubkey, max_base_allocation: u64) -> [u8; 32] {
+        keccak::hashv(&[user.as_ref(), &max_base_allocation.to_le_bytes()]).0
+    }
+
+    fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[a, b]).0
+        } else {
+            keccak::hashv(&[b, a]).0
+        }
+    }
+
+    // (users, leaves, root)
+    type Allowlist = (Vec<(Pubkey, u64)>, [[u8; 32]; 3], [u8; 32]);
+
+    // Three users, (user 0, user 1) are hashed together and then with user 2
+    fn allowlist() -> Allowlist {
+        let users: Vec<(Pubkey, u64)> = (0..3)
+            .map(|i| (Pubkey::new_unique(), 1_000 * (i + 1)))
+            .collect();
+        let leaves = [
+            leaf(&users[0].0, users[0].1),
+            leaf(&users[1].0, users[1].1),
+            leaf(&users[2].0, users[2].1),
+        ];
+        let root = parent(&parent(&leaves[0], &leaves[1]), &leaves[2]);
+        (users, leaves, root)
+    }
+
+    #[test]
+    fn allowlist_proofs_verify_for_every_user() {
+        let (users, leaves, root) = allowlist();
+        let proofs = [
+            vec![leaves[1], leaves[2]],
+            vec![leaves[0], leaves[2]],
+            vec![parent(&leaves[0], &leaves[1])],
+        ];
+        for ((user, max_base_allocation), proof) in users.iter().zip(proofs.iter()) {
+            assert!(is_on_allowlist(&root, proof, user, *max_base_allocation));
+        }
+    }
+
+    #[test]
+    fn allowlist_rejects_the_wrong_user_allocation_or_proof() {
+        let (users, leaves, root) = allowlist();
+        let proof = vec![leaves[1], leaves[2]];
+        assert!(!is_on_allowlist(&root, &proof, &users[0].0, users[0].1 + 1));
+        assert!(!is_on_allowlist(
+            &root,
+            &proof,
+            &Pubkey::new_unique(),
+            users[0].1
+        ));
+        assert!(!is_on_allowlist(
+            &root,
+            &proof[..1],
+            &users[0].0,
+            users[0].1
+        ));
+        assert!(!is_on_allowlist(&root, &[], &users[0].0, users[0].1));
+    }
+
+    fn bidder(max_base_allocation: u64, base_qty_ordered: u64) -> OpenOrders {
+        OpenOrders {
+            side: Side::Bid,
+            max_base_allocation,
+            base_qty_ordered,
+            ..OpenOrders::default()
+        }
+    }
+
+    #[test]
+    fn max_base_for_user_takes_the_lower_nonzero_limit() {
+        let mut auction = Auction::default();
+        assert_eq!(max_base_for_user(&auction, &bidder(0, 0)), None);
+        assert_eq!(max_base_for_user(&auction, &bidder(500, 0)), Some(500));
+        auction.max_base_per_user = 300;
+        assert_eq!(max_base_for_user(&auction, &bidder(0, 0)), Some(300));
+        assert_eq!(max_base_for_user(&auction, &bidder(500, 0)), Some(300));
+        assert_eq!(max_base_for_user(&auction, &bidder(200, 0)), Some(200));
+    }
+
+    #[test]
+    fn bids_cant_add_up_to_more_than_the_user_limit() {
+        let auction = Auction {
+            max_base_per_user: 300,
+            ..Auction::default()
+        };
+        assert!(has_space_under_max_base_per_user(&auction, &bidder(0, 200), 100).is_ok());
+        assert!(has_space_under_max_base_per_user(&auction, &bidder(0, 200), 101).is_err());
+        let asker = OpenOrders {
+            side: Side::Ask,
+            ..bidder(0, 200)
+        };
+        assert!(has_space_under_max_base_per_user(&auction, &asker, 101).is_ok());
+    }
+
+    #[test]
+    fn committee_auctions_need_the_rebuilt_secret_key() {
+        let mut auction = Auction::default();
+        assert!(has_enough_key_shares(&auction));
+        auction.committee = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(!has_enough_key_shares(&auction));
+        auction.committee_secret_key = [1; 32];
+        assert!(has_enough_key_shares(&auction));
+    }
+}
@@ -3,9 +3,10 @@ pub const QUOTE_VAULT: &str = "quote_vault";
 pub const AUCTION: &str = "auction";
 pub const OPEN_ORDERS: &str = "open_orders";
 pub const ORDER_HISTORY: &str = "order_history";
+pub const VESTING: &str = "vesting";
 
-pub const CALLBACK_INFO_LEN: usize = 32;
-pub const CALLBACK_ID_LEN: usize = 32;
+pub const MAX_COMMITTEE_SIZE: usize = 10;
 
-pub const BASE_DUST_THRESHOLD: u64 = 10;
-pub const QUOTE_DUST_THRESHOLD: u64 = 10;
+// Open orders pubkey, followed by the exact quote refund for bids in Out events
+pub const CALLBACK_INFO_LEN: usize = 40;
+pub const CALLBACK_ID_LEN: usize = 32;
@@ -0,0 +1,320 @@
+use std::convert::TryInto;
+
+use anchor_lang::prelude::*;
+
+use curve25519_dalek::constants::{ED25519_BASEPOINT_TABLE, X25519_BASEPOINT};
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::VartimeMultiscalarMul;
+use salsa20::cipher::generic_array::GenericArray;
+use salsa20::hsalsa20;
+
+use crate::error::CustomErrors;
+use crate::types::*;
+
+// NaCl box keys worked out on chain from a secret key, so the secret can be
+// checked against the public key the orders were encrypted to before any
+// decryption is attempted. XSalsa20Poly1305 isn't key committing, a key that
+// hasn't been checked could open the same cipher text to different orders.
+// Curve operations are expensive, callers need a raised compute budget.
+
+/// Applies X25519's clamping to a NaCl secret key
+pub fn clamp_secret_key(secret_key: [u8; 32]) -> Scalar {
+    let mut bytes = secret_key;
+    bytes[0] &= 248;
+    bytes[31] &= 127;
+    bytes[31] |= 64;
+    Scalar::from_bits(bytes)
+}
+
+pub fn public_key(secret: &Scalar) -> [u8; 32] {
+    (X25519_BASEPOINT * secret).to_bytes()
+}
+
+/// The key NaCl's box precomputes, HSalsa20 of the X25519 shared point.
+/// Either side of the exchange gets the same key.
+pub fn nacl_shared_key(secret: &Scalar, public_key: &[u8]) -> Result<[u8; 32]> {
+    let public_key: [u8; 32] = public_key
+        .try_into()
+        .map_err(|_| error!(CustomErrors::InvalidSharedKey))?;
+    let shared_point = MontgomeryPoint(public_key) * secret;
+    let shared_key = hsalsa20(
+        GenericArray::from_slice(shared_point.as_bytes()),
+        &GenericArray::default(),
+    );
+    Ok(shared_key.into())
+}
+
+// Committee auctions don't have a dealer. Each member deals a random
+// polynomial to the others and a member's share is the sum of what it was
+// dealt, so the combined secret key is the sum of the members' secrets and
+// nobody ever holds it. Member i's share is the summed polynomial at i + 1.
+// Public shares are ed25519 points rather than X25519 ones so a share with
+// its sign flipped doesn't pass as the member's share.
+
+pub fn is_valid_public_share(public_share: &[u8; 32]) -> bool {
+    CompressedEdwardsY(*public_share).decompress().is_some()
+}
+
+/// Checks the public shares all lie on one polynomial of degree threshold - 1
+/// whose constant term is the secret key of nacl_pubkey. Then any threshold of
+/// verified key shares rebuild the key the orders were encrypted to.
+pub fn public_shares_match_committee_key(
+    public_shares: &[[u8; 32]],
+    threshold: usize,
+    nacl_pubkey: &[u8],
+) -> bool {
+    let points = match public_shares
+        .iter()
+        .map(|public_share| CompressedEdwardsY(*public_share).decompress())
+        .collect::<Option<Vec<EdwardsPoint>>>()
+    {
+        Some(points) => points,
+        None => return false,
+    };
+    if threshold == 0 || threshold > points.len() {
+        return false;
+    }
+    let xs: Vec<Scalar> = (0..threshold).map(member_x).collect();
+    let interpolate = |at: Scalar| {
+        EdwardsPoint::vartime_multiscalar_mul(
+            (0..threshold).map(|i| lagrange_coefficient(&xs, i, at)),
+            &points[..threshold],
+        )
+    };
+    interpolate(Scalar::zero()).to_montgomery().as_bytes()[..] == nacl_pubkey[..]
+        && points
+            .iter()
+            .enumerate()
+            .skip(threshold)
+            .all(|(member_idx, point)| interpolate(member_x(member_idx)) == *point)
+}
+
+pub fn verify_key_share(share: &[u8; 32], public_share: &[u8; 32]) -> bool {
+    match Scalar::from_canonical_bytes(*share) {
+        Some(share) => (&ED25519_BASEPOINT_TABLE * &share).compress().to_bytes() == *public_share,
+        None => false,
+    }
+}
+
+/// Lagrange interpolation at zero of exactly threshold verified key shares
+pub fn combine_key_shares(key_shares: &[KeyShare]) -> Scalar {
+    let xs: Vec<Scalar> = key_shares
+        .iter()
+        .map(|key_share| member_x(key_share.member_idx as usize))
+        .collect();
+    key_shares
+        .iter()
+        .enumerate()
+        .map(|(i, key_share)| {
+            lagrange_coefficient(&xs, i, Scalar::zero()) * Scalar::from_bits(key_share.share)
+        })
+        .sum()
+}
+
+fn member_x(member_idx: usize) -> Scalar {
+    Scalar::from(member_idx as u64 + 1)
+}
+
+/// Weight of the value at xs[i] when interpolating the polynomial through xs at `at`
+fn lagrange_coefficient(xs: &[Scalar], i: usize, at: Scalar) -> Scalar {
+    xs.iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .fold(Scalar::one(), |coefficient, (_, x_j)| {
+            coefficient * (at - x_j) * (xs[i] - x_j).invert()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // X25519 keys from RFC 7748 and the box key NaCl precomputes for them
+    const ALICE_SECRET_KEY: [u8; 32] = [
+        0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2, 0x66,
+        0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5, 0x1d, 0xb9,
+        0x2c, 0x2a,
+    ];
+    const ALICE_PUBLIC_KEY: [u8; 32] = [
+        0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e, 0xf7,
+        0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e, 0xaa, 0x9b,
+        0x4e, 0x6a,
+    ];
+    const BOB_SECRET_KEY: [u8; 32] = [
+        0x5d, 0xab, 0x08, 0x7e, 0x62, 0x4a, 0x8a, 0x4b, 0x79, 0xe1, 0x7f, 0x8b, 0x83, 0x80, 0x0e,
+        0xe6, 0x6f, 0x3b, 0xb1, 0x29, 0x26, 0x18, 0xb6, 0xfd, 0x1c, 0x2f, 0x8b, 0x27, 0xff, 0x88,
+        0xe0, 0xeb,
+    ];
+    const BOB_PUBLIC_KEY: [u8; 32] = [
+        0xde, 0x9e, 0xdb, 0x7d, 0x7b, 0x7d, 0xc1, 0xb4, 0xd3, 0x5b, 0x61, 0xc2, 0xec, 0xe4, 0x35,
+        0x37, 0x3f, 0x83, 0x43, 0xc8, 0x5b, 0x78, 0x67, 0x4d, 0xad, 0xfc, 0x7e, 0x14, 0x6f, 0x88,
+        0x2b, 0x4f,
+    ];
+    const SHARED_KEY: [u8; 32] = [
+        0x1b, 0x27, 0x55, 0x64, 0x73, 0xe9, 0x85, 0xd4, 0x62, 0xcd, 0x51, 0x19, 0x7a, 0x9a, 0x46,
+        0xc7, 0x60, 0x09, 0x54, 0x9e, 0xac, 0x64, 0x74, 0xf2, 0x06, 0xc4, 0xee, 0x08, 0x44, 0xf6,
+        0x83, 0x89,
+    ];
+
+    #[test]
+    fn public_keys_match_nacl() {
+        assert_eq!(
+            public_key(&clamp_secret_key(ALICE_SECRET_KEY)),
+            ALICE_PUBLIC_KEY
+        );
+        assert_eq!(
+            public_key(&clamp_secret_key(BOB_SECRET_KEY)),
+            BOB_PUBLIC_KEY
+        );
+    }
+
+    #[test]
+    fn shared_key_matches_nacl_from_either_side() {
+        let alice = clamp_secret_key(ALICE_SECRET_KEY);
+        let bob = clamp_secret_key(BOB_SECRET_KEY);
+        assert_eq!(
+            nacl_shared_key(&alice, &BOB_PUBLIC_KEY).unwrap(),
+            SHARED_KEY
+        );
+        assert_eq!(
+            nacl_shared_key(&bob, &ALICE_PUBLIC_KEY).unwrap(),
+            SHARED_KEY
+        );
+    }
+
+    #[test]
+    fn shared_key_needs_a_32_byte_public_key() {
+        let alice = clamp_secret_key(ALICE_SECRET_KEY);
+        assert!(nacl_shared_key(&alice, &BOB_PUBLIC_KEY[..31]).is_err());
+    }
+
+    // A 2 of 3 committee where each member deals a degree 1 polynomial,
+    // member i's polynomial is secret + slope * x
+    const MEMBER_POLYNOMIALS: [(u64, u64); 3] = [(1_234_567, 89), (7_654_321, 3), (42, 1_000)];
+
+    fn committee_secret() -> Scalar {
+        MEMBER_POLYNOMIALS
+            .iter()
+            .map(|(secret, _)| Scalar::from(*secret))
+            .sum()
+    }
+
+    fn key_shares() -> Vec<KeyShare> {
+        (0..3u8)
+            .map(|member_idx| {
+                let x = Scalar::from(member_idx as u64 + 1);
+                let share: Scalar = MEMBER_POLYNOMIALS
+                    .iter()
+                    .map(|(secret, slope)| Scalar::from(*secret) + Scalar::from(*slope) * x)
+                    .sum();
+                KeyShare {
+                    member_idx,
+                    share: share.to_bytes(),
+                }
+            })
+            .collect()
+    }
+
+    fn public_share(key_share: &KeyShare) -> [u8; 32] {
+        (&ED25519_BASEPOINT_TABLE * &Scalar::from_bits(key_share.share))
+            .compress()
+            .to_bytes()
+    }
+
+    fn public_shares() -> Vec<[u8; 32]> {
+        key_shares().iter().map(public_share).collect()
+    }
+
+    fn committee_public_key(secret: &Scalar) -> [u8; 32] {
+        (&ED25519_BASEPOINT_TABLE * secret)
+            .to_montgomery()
+            .to_bytes()
+    }
+
+    #[test]
+    fn committee_key_is_the_sum_of_the_members_keys() {
+        let members_key: EdwardsPoint = MEMBER_POLYNOMIALS
+            .iter()
+            .map(|(secret, _)| &ED25519_BASEPOINT_TABLE * &Scalar::from(*secret))
+            .sum();
+        assert_eq!(
+            members_key.to_montgomery().to_bytes(),
+            committee_public_key(&committee_secret())
+        );
+    }
+
+    #[test]
+    fn key_shares_verify_against_their_own_public_share_only() {
+        let key_shares = key_shares();
+        for key_share in key_shares.iter() {
+            assert!(is_valid_public_share(&public_share(key_share)));
+            assert!(verify_key_share(&key_share.share, &public_share(key_share)));
+        }
+        assert!(!verify_key_share(
+            &key_shares[0].share,
+            &public_share(&key_shares[1])
+        ));
+        // Not a canonical scalar
+        assert!(!verify_key_share(
+            &[0xff; 32],
+            &public_share(&key_shares[0])
+        ));
+    }
+
+    #[test]
+    fn public_shares_have_to_interpolate_to_the_committee_key() {
+        let nacl_pubkey = committee_public_key(&committee_secret());
+        assert!(public_shares_match_committee_key(
+            &public_shares(),
+            2,
+            &nacl_pubkey
+        ));
+        assert!(public_shares_match_committee_key(
+            &public_shares(),
+            3,
+            &nacl_pubkey
+        ));
+        // A key the shares weren't dealt for
+        assert!(!public_shares_match_committee_key(
+            &public_shares(),
+            2,
+            &committee_public_key(&Scalar::from(5u64))
+        ));
+        // The last share isn't on the same polynomial as the first two
+        let mut off_polynomial = public_shares();
+        off_polynomial[2] = off_polynomial[1];
+        assert!(!public_shares_match_committee_key(
+            &off_polynomial,
+            2,
+            &nacl_pubkey
+        ));
+        // One share can't stand in for a degree 1 polynomial
+        assert!(!public_shares_match_committee_key(
+            &public_shares()[..1],
+            1,
+            &nacl_pubkey
+        ));
+    }
+
+    #[test]
+    fn any_threshold_of_key_shares_rebuilds_the_secret() {
+        let key_shares = key_shares();
+        for (i, j) in [(0, 1), (0, 2), (1, 2)].iter() {
+            let combined = combine_key_shares(&[key_shares[*i], key_shares[*j]]);
+            assert_eq!(combined, committee_secret());
+        }
+        assert_ne!(combine_key_shares(&key_shares[..1]), committee_secret());
+    }
+
+    #[test]
+    fn committee_key_opens_orders_encrypted_to_its_public_key() {
+        let secret = committee_secret();
+        let user = clamp_secret_key(ALICE_SECRET_KEY);
+        assert_eq!(
+            nacl_shared_key(&secret, &ALICE_PUBLIC_KEY).unwrap(),
+            nacl_shared_key(&user, &committee_public_key(&secret)).unwrap()
+        );
+    }
+}
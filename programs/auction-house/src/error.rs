@@ -92,6 +92,86 @@ pub enum CustomErrors {
     NumericalOverflow,
     #[msg("Slab iterator stack overflow")]
     SlabIteratorOverflow,
+    // Deprecated, lot sizes handle mints with different decimals now. Kept so
+    // that the error codes after it don't change
     #[msg("Base & quote mint need to have the same decimals")]
     IncompatibleMintDecimals,
+    #[msg("Reserve price must be a multiple of the assigned tick size")]
+    ReservePriceNotAMultipleOfTickSize,
+    #[msg("Auction can have either a public or a hidden reserve price, not both")]
+    InvalidReservePrice,
+    #[msg("Auction doesn't have a hidden reserve price to reveal")]
+    NoEncryptedReservePrice,
+    #[msg("Reserve price and salt don't match the reserve price commitment")]
+    InvalidReservePriceKey,
+    #[msg("Fixed supply auctions don't take ask orders")]
+    AskOrdersNotAllowed,
+    #[msg("This is not a fixed supply auction")]
+    NotFixedSupplyAuction,
+    #[msg("Fixed supply has already been deposited")]
+    FixedSupplyAlreadyDeposited,
+    #[msg("Auction proceeds have already been withdrawn")]
+    ProceedsAlreadyWithdrawn,
+    #[msg("Orders add up to more than the maximum base quantity allowed per user")]
+    MaxBasePerUserExceeded,
+    #[msg("Soft close window and extensions can't be negative")]
+    InvalidSoftCloseParams,
+    #[msg("Allowlist proof doesn't match the auction's allowlist root")]
+    InvalidAllowlistProof,
+    #[msg("Vesting cliff and duration can't be negative and the cliff can't be longer than the duration")]
+    InvalidVestingParams,
+    #[msg("Auction doesn't have a vesting schedule")]
+    NoVestingSchedule,
+    #[msg("No vested tokens left to claim")]
+    NoVestedTokensToClaim,
+    #[msg("Auction has been cancelled")]
+    AuctionCancelled,
+    #[msg("Auction can only be updated before the order phase starts")]
+    AuctionAlreadyStarted,
+    #[msg("Auction id is part of the auction's address and can't be changed")]
+    AuctionIdCannotChange,
+    #[msg("Base and quote lot sizes should be greater than zero")]
+    InvalidLotSize,
+    #[msg("Token accounts and mints need to belong to the token program or token-2022")]
+    InvalidTokenProgram,
+    #[msg("Only the transfer fee and mint close authority token-2022 extensions are supported")]
+    UnsupportedMintExtension,
+    #[msg("Only bids can be placed with a quote budget")]
+    QuoteBudgetOnlyForBids,
+    #[msg("Every open orders account needs to be settled first")]
+    OpenOrdersNotSettled,
+    #[msg("Auction proceeds need to be withdrawn first")]
+    ProceedsNotWithdrawn,
+    #[msg("Remaining accounts don't match the (user, open orders, order history, vesting, user quote, user base) layout")]
+    InvalidSettleAccounts,
+    #[msg("AOB accounts need to be closed first")]
+    AobAccountsNotClosed,
+    #[msg("Vaults need to be empty, withdraw the proceeds and sweep the dust first")]
+    VaultsNotEmpty,
+    #[msg("Commit reveal auctions need an encrypted side and the penalty can't be over 10000 bps")]
+    InvalidCommitRevealParams,
+    #[msg("Only available in commit reveal auctions")]
+    CommitRevealAuctionsOnly,
+    #[msg("Orders in commit reveal auctions are revealed by their users")]
+    NotAvailableForCommitReveal,
+    #[msg("Revealed order doesn't match its commitment")]
+    InvalidCommitment,
+    #[msg("Committee can have at most 10 unique members with valid public shares and the threshold should be between 1 and the committee size")]
+    InvalidCommitteeParams,
+    #[msg("Committee size is part of the auction account's size and can't be changed")]
+    CommitteeSizeCannotChange,
+    #[msg("Signer isn't a member of the auction's committee")]
+    NotACommitteeMember,
+    #[msg("Key share has already been submitted")]
+    KeyShareAlreadySubmitted,
+    #[msg("Not enough committee key shares have been submitted to decrypt")]
+    NotEnoughKeyShares,
+    #[msg("Vesting account can only be closed once all of its base has been claimed")]
+    VestedTokensNotClaimed,
+    #[msg("Key share doesn't match the member's public share")]
+    InvalidKeyShare,
+    #[msg("At least one order needs to be placed")]
+    NoOrdersToPlace,
+    #[msg("Auction can't be updated once users have open orders accounts")]
+    AuctionHasOpenOrders,
 }
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::types::*;
+
+// Quantities are in lots and prices are FP32 quote lots per base lot, same as
+// the auction and open orders accounts
+
+#[event]
+pub struct AuctionCreated {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+    pub auction_id: [u8; 10],
+    pub quote_mint: Pubkey,
+    pub base_mint: Pubkey,
+    pub start_order_phase: i64,
+    pub end_order_phase: i64,
+    pub end_decryption_phase: i64,
+}
+
+#[event]
+pub struct OrderPlaced {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub user: Pubkey,
+    pub side: Side,
+    pub order_id: u128,
+    pub limit_price: u64,
+    pub base_qty: u64,
+    pub quote_qty: u64,
+}
+
+#[event]
+pub struct EncryptedOrderPlaced {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub user: Pubkey,
+    pub side: Side,
+    pub token_qty: u64,
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub user: Pubkey,
+    pub side: Side,
+    pub order_id: u128, // Zero for encrypted orders
+    pub is_encrypted: bool,
+    // Encrypted orders only know the quantity of the token locked
+    pub base_qty: u64,
+    pub quote_qty: u64,
+}
+
+#[event]
+pub struct KeyShareSubmitted {
+    pub auction: Pubkey,
+    pub member: Pubkey,
+    pub num_key_shares: u8,
+}
+
+#[event]
+pub struct OrdersDecrypted {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub num_orders: u8,
+    pub base_qty: u64,
+    pub quote_qty: u64,
+}
+
+#[event]
+pub struct ClearingPriceFound {
+    pub auction: Pubkey,
+    pub clearing_price: u64,
+    pub total_quantity_matched: u64,
+    pub has_failed: bool,
+}
+
+#[event]
+pub struct OrderFilled {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub side: Side,
+    pub order_id: u128,
+    pub base_size: u64,
+    pub quote_size: u64,
+}
+
+#[event]
+pub struct OrderOut {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub side: Side,
+    pub order_id: u128,
+    pub base_size: u64,
+    pub quote_refund: u64,
+}
+
+#[event]
+pub struct Settled {
+    pub auction: Pubkey,
+    pub open_orders: Pubkey,
+    pub user: Pubkey,
+    pub quote_amount_returned: u64,
+    pub base_amount_returned: u64,
+    pub base_amount_vesting: u64,
+}
+
+#[event]
+pub struct AuctionClosed {
+    pub auction: Pubkey,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+
+#[derive(Accounts)]
+pub struct AbortAuction<'info> {
+    pub auctioneer: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+}
+
+impl AbortAuction<'_> {
+    // Can be called in any phase up until the clearing price is found, after
+    // that orders are already being matched
+    pub fn access_control(&self) -> Result<()> {
+        if self.auction.is_cancelled {
+            return Err(error!(CustomErrors::AuctionCancelled));
+        }
+        if self.auction.has_found_clearing_price {
+            return Err(error!(CustomErrors::ClearingPriceAlreadyFound));
+        }
+        Ok(())
+    }
+}
+
+pub fn abort_auction(ctx: Context<AbortAuction>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    auction.is_cancelled = true;
+    // Lets the permissionless match orders crank take every order off the
+    // book without filling any of them, instead of waiting on users to cancel
+    auction.remaining_bid_fills = 0;
+    auction.remaining_ask_fills = 0;
+    Ok(())
+}
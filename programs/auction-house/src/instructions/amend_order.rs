@@ -0,0 +1,221 @@
+use anchor_lang::prelude::*;
+
+use agnostic_orderbook::critbit::{LeafNode, Node};
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::{
+    get_side_from_order_id, EventQueue, EventQueueHeader, EVENT_QUEUE_HEADER_LEN,
+};
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::instructions::{extend_order_phase, NewOrder};
+use crate::program_accounts::*;
+use crate::token_interface::{amount_with_transfer_fee, transfer_checked};
+use crate::types::*;
+
+impl NewOrder<'_> {
+    pub fn access_control_amend_order(
+        &self,
+        order_id: &u128,
+        limit_price: u64,
+        max_base_qty: u64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+        let open_orders = self.open_orders.clone().into_inner();
+
+        if !is_order_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::OrderPhaseNotActive));
+        }
+        normal_orders_only(&auction, &open_orders)?;
+        validate_price_and_qty(&auction, limit_price, max_base_qty)?;
+
+        // Validate the order id is present, will error inside function if not
+        let _ = self.open_orders.find_order_index(order_id)?;
+
+        Ok(())
+    }
+}
+
+/// Replaces an order with a new price and quantity, only the difference
+/// between the old and new locked amounts is transferred. Shrinking an order
+/// at the same price keeps its order id and its place in the queue, any other
+/// change is posted as a new order.
+pub fn amend_order(
+    ctx: Context<NewOrder>,
+    order_id: u128,
+    limit_price: u64,
+    max_base_qty: u64,
+) -> Result<()> {
+    let mut order_book = OrderBookState::new_safe(
+        &ctx.accounts.bids.to_account_info(),
+        &ctx.accounts.asks.to_account_info(),
+        CALLBACK_INFO_LEN,
+        CALLBACK_ID_LEN,
+    )?;
+    let header = {
+        let mut event_queue_data: &[u8] =
+            &ctx.accounts.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+        EventQueueHeader::deserialize(&mut event_queue_data)
+            .unwrap()
+            .check()?
+    };
+    let mut event_queue = EventQueue::new_safe(
+        header,
+        &ctx.accounts.event_queue.to_account_info(),
+        CALLBACK_INFO_LEN,
+    )?;
+
+    // Take the old order off the book
+    let slab = order_book.get_tree(get_side_from_order_id(order_id));
+    let (node, _) = slab
+        .remove_by_key(order_id)
+        .ok_or_else(|| error!(CustomErrors::OrderIdNotFound))?;
+    let leaf_node = *node.as_leaf().unwrap();
+    let old_base_qty = leaf_node.base_quantity;
+    let old_quote_qty = bid_quote_qty(leaf_node.base_quantity, leaf_node.price())?;
+
+    let open_orders = &mut *ctx.accounts.open_orders;
+    emit!(OrderCancelled {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id,
+        is_encrypted: false,
+        base_qty: old_base_qty,
+        quote_qty: old_quote_qty,
+    });
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_sub(old_base_qty)
+        .unwrap();
+    has_space_under_max_base_per_user(
+        &ctx.accounts.auction.clone().into_inner(),
+        open_orders,
+        max_base_qty,
+    )?;
+
+    let is_size_decrease = limit_price == leaf_node.price() && max_base_qty < old_base_qty;
+    let (new_order_id, new_base_qty) = if is_size_decrease {
+        // Put the same leaf back with less base, its key holds the time priority
+        order_book
+            .get_tree(get_side_from_order_id(order_id))
+            .insert_leaf(&Node::Leaf(LeafNode {
+                base_quantity: max_base_qty,
+                ..leaf_node
+            }))
+            .unwrap();
+        (order_id, max_base_qty)
+    } else {
+        // Post the replacement order
+        let params = open_orders.new_order_params(limit_price, max_base_qty, u64::MAX);
+        let order_summary = order_book
+            .new_order(
+                params,
+                &mut event_queue,
+                ctx.accounts.auction.min_base_order_size,
+            )
+            .unwrap();
+        (
+            order_summary.posted_order_id.unwrap(),
+            order_summary.total_base_qty,
+        )
+    };
+    let order_idx = open_orders.find_order_index(&order_id)?;
+    open_orders.orders[order_idx] = new_order_id;
+    *open_orders.find_order_record(&order_id)? =
+        OrderRecord::new(new_order_id, limit_price, new_base_qty);
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_add(new_base_qty)
+        .unwrap();
+
+    // Emitted before the transfers, they need ctx.accounts back from open_orders
+    emit!(OrderPlaced {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id: new_order_id,
+        limit_price,
+        base_qty: new_base_qty,
+        quote_qty: bid_quote_qty(new_base_qty, limit_price)?,
+    });
+
+    match open_orders.side {
+        Side::Ask => {
+            open_orders.base_token_locked = open_orders
+                .base_token_locked
+                .checked_sub(old_base_qty)
+                .unwrap()
+                .checked_add(new_base_qty)
+                .unwrap();
+            if new_base_qty > old_base_qty {
+                transfer_checked(
+                    ctx.accounts.transfer_user_base(),
+                    amount_with_transfer_fee(
+                        &ctx.accounts.base_mint,
+                        ctx.accounts
+                            .auction
+                            .base_lots_to_atoms(new_base_qty - old_base_qty),
+                    )?,
+                )?;
+            } else if old_base_qty > new_base_qty {
+                transfer_checked(
+                    ctx.accounts
+                        .transfer_base_vault()
+                        .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                    ctx.accounts
+                        .auction
+                        .base_lots_to_atoms(old_base_qty - new_base_qty),
+                )?;
+            }
+        }
+        Side::Bid => {
+            let new_quote_qty = bid_quote_qty(new_base_qty, limit_price)?;
+            open_orders.quote_token_locked = open_orders
+                .quote_token_locked
+                .checked_sub(old_quote_qty)
+                .unwrap()
+                .checked_add(new_quote_qty)
+                .unwrap();
+            if new_quote_qty > old_quote_qty {
+                transfer_checked(
+                    ctx.accounts.transfer_user_quote(),
+                    amount_with_transfer_fee(
+                        &ctx.accounts.quote_mint,
+                        ctx.accounts
+                            .auction
+                            .quote_lots_to_atoms(new_quote_qty - old_quote_qty),
+                    )?,
+                )?;
+            } else if old_quote_qty > new_quote_qty {
+                transfer_checked(
+                    ctx.accounts
+                        .transfer_quote_vault()
+                        .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                    ctx.accounts
+                        .auction
+                        .quote_lots_to_atoms(old_quote_qty - new_quote_qty),
+                )?;
+            }
+        }
+    }
+
+    order_book.commit_changes();
+    let mut event_queue_header_data: &mut [u8] = &mut ctx.accounts.event_queue.data.borrow_mut();
+    event_queue
+        .header
+        .serialize(&mut event_queue_header_data)
+        .unwrap();
+
+    // Only reposted orders push the soft close back
+    if !is_size_decrease {
+        extend_order_phase(&mut ctx.accounts.auction, Clock::get()?)?;
+    }
+
+    Ok(())
+}
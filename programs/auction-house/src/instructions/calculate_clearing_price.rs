@@ -1,3 +1,4 @@
+use std::cmp;
 use std::convert::TryInto;
 
 use anchor_lang::prelude::*;
@@ -5,11 +6,14 @@ use anchor_lang::prelude::*;
 use agnostic_orderbook::critbit::LeafNode;
 use agnostic_orderbook::orderbook::OrderBookState;
 use agnostic_orderbook::state::Side as AobSide;
+use bonfida_utils::fp_math::fp32_mul;
 
 use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
+use crate::types::*;
 
 #[derive(Accounts)]
 pub struct CalculateClearingPrice<'info> {
@@ -52,6 +56,25 @@ impl CalculateClearingPrice<'_> {
 pub fn calculate_clearing_price(ctx: Context<CalculateClearingPrice>, limit: u16) -> Result<()> {
     let auction = &mut ctx.accounts.auction;
 
+    if auction.reserve_price_commitment != [0; 32] {
+        // Nobody can check a reserve price that was never revealed, so the
+        // auction fails and every order is refunded in full
+        msg!("Hidden reserve price was never revealed");
+        auction.has_failed = true;
+        auction.remaining_bid_fills = 0;
+        auction.remaining_ask_fills = 0;
+        auction.has_found_clearing_price = true;
+        return finalize_clearing_price(auction);
+    }
+    if auction.is_fixed_supply() && !auction.has_deposited_fixed_supply {
+        msg!("Fixed supply was never deposited");
+        auction.has_found_clearing_price = true;
+        return finalize_clearing_price(auction);
+    }
+    // Asks below the reserve price are treated as if they were placed at the reserve price
+    let reserve_price = auction.reserve_price;
+    let ask_price = |ask: &ClearingAsk| cmp::max(ask.price, reserve_price);
+
     let mut order_book = OrderBookState::new_safe(
         &ctx.accounts.bids.to_account_info(),
         &ctx.accounts.asks.to_account_info(),
@@ -68,8 +91,6 @@ pub fn calculate_clearing_price(ctx: Context<CalculateClearingPrice>, limit: u16
     } else {
         bid_slab.clone().into_iter(false)
     };
-    let mut current_bid: LeafNode;
-
     let ask_slab = order_book.get_tree(AobSide::Ask);
     let mut ask_iter = if auction.ask_search_stack_depth > 0 {
         ask_slab.clone().resume_iter(
@@ -79,115 +100,234 @@ pub fn calculate_clearing_price(ctx: Context<CalculateClearingPrice>, limit: u16
     } else {
         ask_slab.clone().into_iter(true)
     };
-    let mut current_ask: LeafNode;
+    let is_pro_rata = auction.fill_allocation_type == FillAllocationTypes::ProRata;
+    // Search stacks the iterators resume from on the next call
+    let mut ask_stack: Vec<u32> = ask_iter.search_stack.clone();
+    let mut bid_stack: Vec<u32> = bid_iter.search_stack.clone();
+    let mut iterations: u16 = 0;
 
-    if auction.current_ask_key == 0 && auction.current_bid_key == 0 {
-        current_bid = match bid_iter.next() {
-            Some(bid) => bid,
-            None => {
-                msg!("No orders found on the bid queue");
-                auction.has_found_clearing_price = true;
-                return Ok(());
-            }
-        };
-        auction.current_bid_key = current_bid.key;
-        current_ask = match ask_iter.next() {
-            Some(ask) => ask,
-            None => {
-                msg!("No orders found on the ask queue");
+    // Once prices have crossed only the rest of the marginal price levels are left
+    if !auction.has_crossed {
+        let mut current_bid: LeafNode;
+        let mut current_ask: ClearingAsk;
+
+        if auction.current_ask_key == 0 && auction.current_bid_key == 0 {
+            current_bid = match bid_iter.next() {
+                Some(bid) => bid,
+                None => {
+                    msg!("No orders found on the bid queue");
+                    auction.has_found_clearing_price = true;
+                    return finalize_clearing_price(auction);
+                }
+            };
+            auction.current_bid_key = current_bid.key;
+            current_ask = if auction.is_fixed_supply() {
+                ClearingAsk::fixed_supply(auction)
+            } else {
+                match ask_iter.next() {
+                    Some(ask) => ask.into(),
+                    None => {
+                        msg!("No orders found on the ask queue");
+                        auction.has_found_clearing_price = true;
+                        return finalize_clearing_price(auction);
+                    }
+                }
+            };
+            auction.current_ask_key = current_ask.key;
+            auction.current_bid_level_quantity = current_bid.base_quantity;
+            auction.current_ask_level_quantity = current_ask.base_quantity;
+            if ask_price(&current_ask) > current_bid.price() {
+                msg!("Orders prices crossed before clearing even started");
                 auction.has_found_clearing_price = true;
-                return Ok(());
+                return finalize_clearing_price(auction);
             }
-        };
-        auction.current_ask_key = current_ask.key;
-        if current_ask.price() > current_bid.price() {
-            msg!("Orders prices crossed before clearing even started");
-            auction.has_found_clearing_price = true;
-            return Ok(());
+        } else {
+            current_bid = bid_iter
+                .find(|this_node| this_node.key == auction.current_bid_key)
+                .ok_or_else(|| error!(CustomErrors::NodeKeyNotFound))?;
+            current_ask = match auction.is_fixed_supply() {
+                true => ClearingAsk::fixed_supply(auction),
+                false => ask_iter
+                    .find(|this_node| this_node.key == auction.current_ask_key)
+                    .ok_or_else(|| error!(CustomErrors::NodeKeyNotFound))?
+                    .into(),
+            };
         }
-    } else {
-        current_bid = bid_iter
-            .find(|this_node| this_node.key == auction.current_bid_key)
-            .ok_or_else(|| error!(CustomErrors::NodeKeyNotFound))?;
-        current_ask = ask_iter
-            .find(|this_node| this_node.key == auction.current_ask_key)
-            .ok_or_else(|| error!(CustomErrors::NodeKeyNotFound))?;
-    }
-
-    // We need to store the stack prior to the loop finishing
-    // because we iterate to the store the next key before ending the loop
-    let mut ask_stack: Vec<u32> = Vec::new();
-    let mut bid_stack: Vec<u32> = Vec::new();
-
-    for _ in 0..limit {
-        ask_stack = ask_iter.search_stack.clone();
-        bid_stack = bid_iter.search_stack.clone();
-        let bid_quantity_remaining = current_bid
-            .base_quantity
-            .checked_sub(auction.current_bid_quantity_filled)
-            .unwrap();
-        let ask_quantity_remaining = current_ask
-            .base_quantity
-            .checked_sub(auction.current_ask_quantity_filled)
-            .unwrap();
-        let is_bid_gte_ask = bid_quantity_remaining >= ask_quantity_remaining;
-        match is_bid_gte_ask {
-            true => {
-                // Ask order is fully filled
-                auction.current_bid_quantity_filled = auction
-                    .current_bid_quantity_filled
-                    .checked_add(ask_quantity_remaining)
-                    .unwrap();
-                auction.total_quantity_filled_so_far = auction
-                    .total_quantity_filled_so_far
-                    .checked_add(ask_quantity_remaining)
-                    .unwrap();
-                match ask_iter.next() {
-                    Some(new_ask) => {
-                        if new_ask.price() > current_bid.price() {
-                            // price have crossed
-                            auction.has_found_clearing_price = true;
-                            break;
+
+        // We need to store the stack prior to the loop finishing
+        // because we iterate to the store the next key before ending the loop
+        while iterations < limit {
+            iterations += 1;
+            ask_stack = ask_iter.search_stack.clone();
+            bid_stack = bid_iter.search_stack.clone();
+            let bid_quantity_remaining = current_bid
+                .base_quantity
+                .checked_sub(auction.current_bid_quantity_filled)
+                .unwrap();
+            let ask_quantity_remaining = current_ask
+                .base_quantity
+                .checked_sub(auction.current_ask_quantity_filled)
+                .unwrap();
+            let is_bid_gte_ask = bid_quantity_remaining >= ask_quantity_remaining;
+            match is_bid_gte_ask {
+                true => {
+                    // Ask order is fully filled
+                    auction.current_bid_quantity_filled = auction
+                        .current_bid_quantity_filled
+                        .checked_add(ask_quantity_remaining)
+                        .unwrap();
+                    auction.total_quantity_filled_so_far = auction
+                        .total_quantity_filled_so_far
+                        .checked_add(ask_quantity_remaining)
+                        .unwrap();
+                    // Fixed supply auctions have no ask orders, only the supply
+                    match ask_iter.next().map(ClearingAsk::from) {
+                        Some(new_ask) => {
+                            if ask_price(&new_ask) > current_bid.price() {
+                                // price have crossed
+                                auction.has_crossed = true;
+                            } else {
+                                if ask_price(&new_ask) == ask_price(&current_ask) {
+                                    auction.current_ask_level_quantity = auction
+                                        .current_ask_level_quantity
+                                        .checked_add(new_ask.base_quantity)
+                                        .unwrap();
+                                } else {
+                                    auction.filled_ask_levels_quantity = auction
+                                        .filled_ask_levels_quantity
+                                        .checked_add(auction.current_ask_level_quantity)
+                                        .unwrap();
+                                    auction.current_ask_level_quantity = new_ask.base_quantity;
+                                }
+                                current_ask = new_ask;
+                                auction.current_ask_key = new_ask.key;
+                                auction.current_ask_quantity_filled = 0;
+                            }
+                        }
+                        None => {
+                            // No more asks
+                            auction.has_crossed = true;
                         }
-                        current_ask = new_ask;
-                        auction.current_ask_key = new_ask.key;
-                        auction.current_ask_quantity_filled = 0;
                     }
-                    None => {
-                        // No more asks
-                        auction.has_found_clearing_price = true;
+                    if auction.has_crossed {
+                        // Whatever is left of the current bid is the first bid to miss out,
+                        // otherwise it's the next bid in the book, found further below
+                        if bid_quantity_remaining > ask_quantity_remaining {
+                            auction.first_losing_bid_price = Some(current_bid.price());
+                        }
+                        auction.is_ask_level_complete = true;
                         break;
                     }
                 }
-            }
-            false => {
-                // Bid order is fully filled
-                auction.current_ask_quantity_filled = auction
-                    .current_ask_quantity_filled
-                    .checked_add(bid_quantity_remaining)
-                    .unwrap();
-                auction.total_quantity_filled_so_far = auction
-                    .total_quantity_filled_so_far
-                    .checked_add(bid_quantity_remaining)
-                    .unwrap();
-                match bid_iter.next() {
-                    Some(new_bid) => {
-                        if current_ask.price() > new_bid.price() {
-                            // price have crossed
-                            auction.has_found_clearing_price = true;
+                false => {
+                    // Bid order is fully filled
+                    auction.current_ask_quantity_filled = auction
+                        .current_ask_quantity_filled
+                        .checked_add(bid_quantity_remaining)
+                        .unwrap();
+                    auction.total_quantity_filled_so_far = auction
+                        .total_quantity_filled_so_far
+                        .checked_add(bid_quantity_remaining)
+                        .unwrap();
+                    match bid_iter.next() {
+                        Some(new_bid) => {
+                            if ask_price(&current_ask) > new_bid.price() {
+                                // price have crossed
+                                auction.has_crossed = true;
+                                auction.first_losing_bid_price = Some(new_bid.price());
+                                auction.is_bid_level_complete = true;
+                                break;
+                            }
+                            if new_bid.price() == current_bid.price() {
+                                auction.current_bid_level_quantity = auction
+                                    .current_bid_level_quantity
+                                    .checked_add(new_bid.base_quantity)
+                                    .unwrap();
+                            } else {
+                                auction.filled_bid_levels_quantity = auction
+                                    .filled_bid_levels_quantity
+                                    .checked_add(auction.current_bid_level_quantity)
+                                    .unwrap();
+                                auction.current_bid_level_quantity = new_bid.base_quantity;
+                            }
+                            current_bid = new_bid;
+                            auction.current_bid_key = new_bid.key;
+                            auction.current_bid_quantity_filled = 0;
+                        }
+                        None => {
+                            // No more bids
+                            auction.has_crossed = true;
+                            auction.is_bid_level_complete = true;
                             break;
                         }
-                        current_bid = new_bid;
-                        auction.current_bid_key = new_bid.key;
-                        auction.current_bid_quantity_filled = 0;
                     }
-                    None => {
-                        // No more bids
-                        auction.has_found_clearing_price = true;
-                        break;
+                }
+            }
+        }
+
+        if auction.has_crossed {
+            auction.final_bid_price = current_bid.price();
+            auction.final_ask_price = ask_price(&current_ask);
+            // From here on the iterators pick up right after the marginal orders
+            ask_stack = ask_iter.search_stack.clone();
+            bid_stack = bid_iter.search_stack.clone();
+            // Only pro rata needs the full ask level, and an empty stack means
+            // there's nothing left to scan on that side
+            if !is_pro_rata || ask_stack.is_empty() {
+                auction.is_ask_level_complete = true;
+            }
+            if bid_stack.is_empty() {
+                auction.is_bid_level_complete = true;
+            }
+        }
+    }
+
+    // Orders at the marginal price the loop never reached still belong to the
+    // marginal level, the first bid at a lower price is also the first losing bid.
+    // These scans share the limit with the loop above so they can be resumed too.
+    if auction.has_crossed {
+        while !auction.is_bid_level_complete && iterations < limit {
+            iterations += 1;
+            match bid_iter.next() {
+                Some(next_bid) => {
+                    if auction.first_losing_bid_price.is_none() {
+                        auction.first_losing_bid_price = Some(next_bid.price());
                     }
+                    if !is_pro_rata || next_bid.price() != auction.final_bid_price {
+                        auction.is_bid_level_complete = true;
+                    } else {
+                        auction.current_bid_level_quantity = auction
+                            .current_bid_level_quantity
+                            .checked_add(next_bid.base_quantity)
+                            .unwrap();
+                    }
+                }
+                None => {
+                    auction.is_bid_level_complete = true;
                 }
             }
+            bid_stack = bid_iter.search_stack.clone();
+            if bid_stack.is_empty() {
+                auction.is_bid_level_complete = true;
+            }
+        }
+        while !auction.is_ask_level_complete && iterations < limit {
+            iterations += 1;
+            match ask_iter.next().map(ClearingAsk::from) {
+                Some(next_ask) if ask_price(&next_ask) == auction.final_ask_price => {
+                    auction.current_ask_level_quantity = auction
+                        .current_ask_level_quantity
+                        .checked_add(next_ask.base_quantity)
+                        .unwrap();
+                }
+                _ => {
+                    auction.is_ask_level_complete = true;
+                }
+            }
+            ask_stack = ask_iter.search_stack.clone();
+            if ask_stack.is_empty() {
+                auction.is_ask_level_complete = true;
+            }
         }
     }
 
@@ -206,20 +346,183 @@ pub fn calculate_clearing_price(ctx: Context<CalculateClearingPrice>, limit: u16
     auction.ask_search_stack_values = [ask_stack, ask_padding].concat().try_into().unwrap();
     auction.bid_search_stack_values = [bid_stack, bid_padding].concat().try_into().unwrap();
 
-    if auction.has_found_clearing_price {
+    if auction.has_crossed && auction.is_bid_level_complete && auction.is_ask_level_complete {
+        auction.has_found_clearing_price = true;
         auction.total_quantity_matched = auction.total_quantity_filled_so_far;
         auction.remaining_bid_fills = auction.total_quantity_filled_so_far;
         auction.remaining_ask_fills = auction.total_quantity_filled_so_far;
-        auction.final_bid_price = current_bid.price();
-        auction.final_ask_price = current_ask.price();
-        // For now clearing price defaults to lowest bid that fills the ask quantity
-        auction.clearing_price = current_bid.price();
-        msg!(
-            "total_quantity_matched: {}, clearing_price: {}",
-            auction.total_quantity_matched,
-            auction.clearing_price
-        );
+        // Orders at better prices than the marginal level are always filled in full,
+        // so whatever is left over gets shared out at the marginal level
+        auction.marginal_bid_quantity = auction.current_bid_level_quantity;
+        auction.marginal_ask_quantity = auction.current_ask_level_quantity;
+        auction.clearing_price = clearing_price(auction);
+        finalize_clearing_price(auction)?;
+    }
+
+    Ok(())
+}
+
+/// Every rule has to land between the final ask and final bid prices, otherwise
+/// match_orders would fill bids above their limit or asks below theirs
+fn clearing_price(auction: &Auction) -> u64 {
+    match auction.final_price_type {
+        FinalPriceTypes::LowestWinningBid => auction.final_bid_price,
+        FinalPriceTypes::HighestWinningAsk => auction.final_ask_price,
+        // Both final prices are on a tick, so rounding half the spread down to
+        // a tick keeps the midpoint on one too
+        FinalPriceTypes::Midpoint => {
+            let half_spread = auction
+                .final_bid_price
+                .checked_sub(auction.final_ask_price)
+                .unwrap()
+                / 2;
+            auction
+                .final_ask_price
+                .checked_add(half_spread - half_spread % auction.tick_size)
+                .unwrap()
+        }
+        FinalPriceTypes::FirstLosingBid => match auction.first_losing_bid_price {
+            None => auction.final_bid_price,
+            Some(price) => cmp::max(price, auction.final_ask_price),
+        },
+    }
+}
+
+/// The parts of an ask needed to find the clearing price. Fixed supply auctions
+/// clear their bids against a single virtual ask for the whole supply.
+#[derive(Clone, Copy)]
+struct ClearingAsk {
+    key: u128,
+    price: u64,
+    base_quantity: u64,
+}
+
+impl ClearingAsk {
+    fn fixed_supply(auction: &Auction) -> Self {
+        ClearingAsk {
+            key: 0,
+            price: auction.reserve_price,
+            base_quantity: auction.fixed_base_supply,
+        }
     }
+}
 
+impl From<LeafNode> for ClearingAsk {
+    fn from(node: LeafNode) -> Self {
+        ClearingAsk {
+            key: node.key,
+            price: node.price(),
+            base_quantity: node.base_quantity,
+        }
+    }
+}
+
+/// Called once the clearing price search is over. All or nothing auctions that
+/// don't reach their minimum raise fill nothing, match_orders then only emits
+/// `Event::Out` so every order is refunded in full.
+fn finalize_clearing_price(auction: &mut Account<Auction>) -> Result<()> {
+    let quote_raised = fp32_mul(auction.total_quantity_matched, auction.clearing_price)
+        .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?;
+    if auction.total_quantity_matched < auction.min_base_matched
+        || quote_raised < auction.min_quote_raised
+    {
+        auction.has_failed = true;
+        auction.remaining_bid_fills = 0;
+        auction.remaining_ask_fills = 0;
+    }
+    emit!(ClearingPriceFound {
+        auction: auction.key(),
+        clearing_price: auction.clearing_price,
+        total_quantity_matched: auction.total_quantity_matched,
+        has_failed: auction.has_failed,
+    });
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crossed_auction(final_price_type: FinalPriceTypes) -> Auction {
+        Auction {
+            final_price_type,
+            final_bid_price: 120,
+            final_ask_price: 100,
+            first_losing_bid_price: Some(110),
+            tick_size: 5,
+            ..Auction::default()
+        }
+    }
+
+    #[test]
+    fn lowest_winning_bid_clears_at_the_final_bid() {
+        assert_eq!(
+            clearing_price(&crossed_auction(FinalPriceTypes::LowestWinningBid)),
+            120
+        );
+    }
+
+    #[test]
+    fn highest_winning_ask_clears_at_the_final_ask() {
+        assert_eq!(
+            clearing_price(&crossed_auction(FinalPriceTypes::HighestWinningAsk)),
+            100
+        );
+    }
+
+    #[test]
+    fn midpoint_rounds_down_to_a_tick() {
+        assert_eq!(
+            clearing_price(&crossed_auction(FinalPriceTypes::Midpoint)),
+            110
+        );
+        let auction = Auction {
+            final_bid_price: 115,
+            ..crossed_auction(FinalPriceTypes::Midpoint)
+        };
+        assert_eq!(clearing_price(&auction), 105);
+        // Less than a tick either side of the midpoint
+        let auction = Auction {
+            final_bid_price: 105,
+            ..crossed_auction(FinalPriceTypes::Midpoint)
+        };
+        assert_eq!(clearing_price(&auction), 100);
+    }
+
+    #[test]
+    fn first_losing_bid_stays_between_the_final_prices() {
+        assert_eq!(
+            clearing_price(&crossed_auction(FinalPriceTypes::FirstLosingBid)),
+            110
+        );
+        // Every bid won
+        let auction = Auction {
+            first_losing_bid_price: None,
+            ..crossed_auction(FinalPriceTypes::FirstLosingBid)
+        };
+        assert_eq!(clearing_price(&auction), 120);
+        // Losing bid below the final ask
+        let auction = Auction {
+            first_losing_bid_price: Some(90),
+            ..crossed_auction(FinalPriceTypes::FirstLosingBid)
+        };
+        assert_eq!(clearing_price(&auction), 100);
+    }
+
+    #[test]
+    fn every_rule_lands_between_the_final_prices() {
+        for final_price_type in [
+            FinalPriceTypes::LowestWinningBid,
+            FinalPriceTypes::HighestWinningAsk,
+            FinalPriceTypes::Midpoint,
+            FinalPriceTypes::FirstLosingBid,
+        ]
+        .iter()
+        {
+            let auction = crossed_auction(*final_price_type);
+            let price = clearing_price(&auction);
+            assert!(auction.final_ask_price <= price && price <= auction.final_bid_price);
+            assert_eq!(price % auction.tick_size, 0);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::get_side_from_order_id;
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::instructions::NewOrder;
+use crate::program_accounts::*;
+use crate::token_interface::transfer_checked;
+use crate::types::*;
+
+impl NewOrder<'_> {
+    // Same timing rules as cancel_order and cancel_encrypted_order, orders
+    // on the book can only be pulled during the order phase
+    pub fn access_control_cancel_all_orders(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+
+        if auction.is_cancelled
+            || auction.end_decryption_phase <= clock.unix_timestamp
+            || is_order_phase_active(clock, &auction)
+        {
+            return Ok(());
+        }
+        Err(error!(CustomErrors::OrderPhaseNotActive))
+    }
+}
+
+/// Cancels every order, plain and encrypted, with a single token transfer
+/// for the total amount unlocked. After the decryption phase only the leftover
+/// encrypted orders are cancelled, orders on the book are left to be matched.
+pub fn cancel_all_orders(ctx: Context<NewOrder>) -> Result<()> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let can_cancel_book_orders =
+        ctx.accounts.auction.is_cancelled || is_order_phase_active(clock, &ctx.accounts.auction);
+    let mut order_book = OrderBookState::new_safe(
+        &ctx.accounts.bids.to_account_info(),
+        &ctx.accounts.asks.to_account_info(),
+        CALLBACK_INFO_LEN,
+        CALLBACK_ID_LEN,
+    )?;
+
+    let open_orders = &mut *ctx.accounts.open_orders;
+    let mut total_base_qty: u64 = 0;
+    let mut total_quote_qty: u64 = 0;
+    let book_orders = match can_cancel_book_orders {
+        true => std::mem::take(&mut open_orders.orders),
+        false => Vec::new(),
+    };
+    for order_id in book_orders.iter() {
+        let slab = order_book.get_tree(get_side_from_order_id(*order_id));
+        let (node, _) = slab
+            .remove_by_key(*order_id)
+            .ok_or_else(|| error!(CustomErrors::OrderIdNotFound))?;
+        let leaf_node = node.as_leaf().unwrap();
+        let quote_qty = bid_quote_qty(leaf_node.base_quantity, leaf_node.price())?;
+        total_base_qty = total_base_qty
+            .checked_add(leaf_node.base_quantity)
+            .unwrap();
+        total_quote_qty = total_quote_qty.checked_add(quote_qty).unwrap();
+        emit!(OrderCancelled {
+            auction: open_orders.auction,
+            open_orders: open_orders.this_open_orders,
+            user: open_orders.authority,
+            side: open_orders.side,
+            order_id: *order_id,
+            is_encrypted: false,
+            base_qty: leaf_node.base_quantity,
+            quote_qty,
+        });
+    }
+    order_book.commit_changes();
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_sub(total_base_qty)
+        .unwrap();
+
+    // Encrypted orders only have their deposit locked
+    let mut total_penalty: u64 = 0;
+    for order in open_orders.encrypted_orders.iter() {
+        let (base_qty, quote_qty) = match open_orders.side {
+            Side::Ask => (order.token_qty, 0),
+            Side::Bid => (0, order.token_qty),
+        };
+        total_base_qty = total_base_qty.checked_add(base_qty).unwrap();
+        total_quote_qty = total_quote_qty.checked_add(quote_qty).unwrap();
+        total_penalty = total_penalty
+            .checked_add(ctx.accounts.auction.unrevealed_penalty(order.token_qty, now))
+            .unwrap();
+        emit!(OrderCancelled {
+            auction: open_orders.auction,
+            open_orders: open_orders.this_open_orders,
+            user: open_orders.authority,
+            side: open_orders.side,
+            order_id: 0,
+            is_encrypted: true,
+            base_qty,
+            quote_qty,
+        });
+    }
+
+    if can_cancel_book_orders {
+        open_orders.order_records = Vec::new();
+    }
+    open_orders.num_orders = open_orders
+        .num_orders
+        .checked_sub((book_orders.len() + open_orders.encrypted_orders.len()) as u8)
+        .unwrap();
+    open_orders.encrypted_orders = Vec::new();
+
+    match open_orders.side {
+        Side::Ask => {
+            open_orders.base_token_locked = open_orders
+                .base_token_locked
+                .checked_sub(total_base_qty)
+                .unwrap();
+            let refund = total_base_qty.checked_sub(total_penalty).unwrap();
+            if refund > 0 {
+                transfer_checked(
+                    ctx.accounts
+                        .transfer_base_vault()
+                        .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                    ctx.accounts.auction.base_lots_to_atoms(refund),
+                )?;
+            }
+        }
+        Side::Bid => {
+            open_orders.quote_token_locked = open_orders
+                .quote_token_locked
+                .checked_sub(total_quote_qty)
+                .unwrap();
+            let refund = total_quote_qty.checked_sub(total_penalty).unwrap();
+            if refund > 0 {
+                transfer_checked(
+                    ctx.accounts
+                        .transfer_quote_vault()
+                        .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                    ctx.accounts.auction.quote_lots_to_atoms(refund),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,15 +1,38 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
 
 use crate::consts::*;
+use crate::events::*;
 use crate::instructions::NewEncryptedOrder;
 use crate::program_accounts::*;
+use crate::token_interface::transfer_checked;
 use crate::types::*;
 
 pub fn cancel_encrypted_order(ctx: Context<NewEncryptedOrder>, order_idx: u8) -> Result<()> {
     let open_orders = &mut *ctx.accounts.open_orders;
     let this_order = open_orders.encrypted_orders.remove(order_idx as usize);
     open_orders.num_orders = open_orders.num_orders.checked_sub(1).unwrap();
+    let (base_qty, quote_qty) = match open_orders.side {
+        Side::Ask => (this_order.token_qty, 0),
+        Side::Bid => (0, this_order.token_qty),
+    };
+    emit!(OrderCancelled {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id: 0,
+        is_encrypted: true,
+        base_qty,
+        quote_qty,
+    });
+    let refund = this_order
+        .token_qty
+        .checked_sub(
+            ctx.accounts
+                .auction
+                .unrevealed_penalty(this_order.token_qty, Clock::get()?.unix_timestamp),
+        )
+        .unwrap();
 
     match open_orders.side {
         Side::Ask => {
@@ -17,11 +40,11 @@ pub fn cancel_encrypted_order(ctx: Context<NewEncryptedOrder>, order_idx: u8) ->
                 .base_token_locked
                 .checked_sub(this_order.token_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts
                     .transfer_base_vault()
                     .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-                this_order.token_qty,
+                ctx.accounts.auction.base_lots_to_atoms(refund),
             )?;
         }
         Side::Bid => {
@@ -29,11 +52,11 @@ pub fn cancel_encrypted_order(ctx: Context<NewEncryptedOrder>, order_idx: u8) ->
                 .quote_token_locked
                 .checked_sub(this_order.token_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts
                     .transfer_quote_vault()
                     .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-                this_order.token_qty,
+                ctx.accounts.auction.quote_lots_to_atoms(refund),
             )?;
         }
     }
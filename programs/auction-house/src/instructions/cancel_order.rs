@@ -1,14 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
 
 use agnostic_orderbook::orderbook::OrderBookState;
 use agnostic_orderbook::state::get_side_from_order_id;
-use bonfida_utils::fp_math::fp32_mul;
 
+use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::instructions::NewOrder;
 use crate::program_accounts::*;
+use crate::token_interface::transfer_checked;
 use crate::types::*;
 
 pub fn cancel_order(ctx: Context<NewOrder>, order_id: u128) -> Result<()> {
@@ -24,13 +25,28 @@ pub fn cancel_order(ctx: Context<NewOrder>, order_id: u128) -> Result<()> {
         .ok_or_else(|| error!(CustomErrors::OrderIdNotFound))?;
     let leaf_node = node.as_leaf().unwrap();
     let total_base_qty = leaf_node.base_quantity;
-    let total_quote_qty = fp32_mul(leaf_node.base_quantity, leaf_node.price()).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
+    let total_quote_qty = bid_quote_qty(leaf_node.base_quantity, leaf_node.price())?;
     order_book.commit_changes();
 
     let open_orders = &mut *ctx.accounts.open_orders;
     let order_idx = open_orders.find_order_index(&order_id)?;
     open_orders.orders.remove(order_idx);
+    open_orders.remove_order_record(&order_id);
     open_orders.num_orders = open_orders.num_orders.checked_sub(1).unwrap();
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_sub(total_base_qty)
+        .unwrap();
+    emit!(OrderCancelled {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id,
+        is_encrypted: false,
+        base_qty: total_base_qty,
+        quote_qty: total_quote_qty,
+    });
 
     match open_orders.side {
         Side::Ask => {
@@ -39,11 +55,11 @@ pub fn cancel_order(ctx: Context<NewOrder>, order_id: u128) -> Result<()> {
                 .base_token_locked
                 .checked_sub(total_base_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts
                     .transfer_base_vault()
                     .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-                total_base_qty,
+                ctx.accounts.auction.base_lots_to_atoms(total_base_qty),
             )?;
         }
         Side::Bid => {
@@ -51,11 +67,11 @@ pub fn cancel_order(ctx: Context<NewOrder>, order_id: u128) -> Result<()> {
                 .quote_token_locked
                 .checked_sub(total_quote_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts
                     .transfer_quote_vault()
                     .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-                total_quote_qty,
+                ctx.accounts.auction.quote_lots_to_atoms(total_quote_qty),
             )?;
         }
     }
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+use crate::token_interface::{get_associated_token_address, transfer_checked, TransferChecked};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    pub user: SystemAccount<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    #[account(
+        seeds = [user.key().as_ref(), VESTING.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = vesting.bump,
+        mut
+    )]
+    pub vesting: Account<'info, Vesting>,
+    // Token Accounts
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.base_vault,
+        mut
+    )]
+    pub base_vault: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
+    #[account(address = auction.base_mint)]
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
+    #[account(
+        address = get_associated_token_address(&user.key(), &auction.base_mint, &auction.base_token_program),
+        mut
+    )]
+    pub user_base: UncheckedAccount<'info>,
+    // Programs
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
+}
+
+impl ClaimVested<'_> {
+    pub fn access_control(&self) -> Result<()> {
+        if !self.auction.has_vesting() {
+            return Err(error!(CustomErrors::NoVestingSchedule));
+        }
+        Ok(())
+    }
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
+            to: self.user_base.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vesting = &mut ctx.accounts.vesting;
+
+    let vested_amount = ctx
+        .accounts
+        .auction
+        .vested_amount(vesting.base_amount_vesting, clock.unix_timestamp);
+    let claimable_amount = vested_amount
+        .checked_sub(vesting.base_amount_claimed)
+        .unwrap();
+    if claimable_amount == 0 {
+        return Err(error!(CustomErrors::NoVestedTokensToClaim));
+    }
+    vesting.base_amount_claimed = vested_amount;
+    ctx.accounts.auction.base_vesting_unclaimed = ctx
+        .accounts
+        .auction
+        .base_vesting_unclaimed
+        .checked_sub(claimable_amount)
+        .unwrap();
+
+    transfer_checked(
+        ctx.accounts
+            .transfer_base_vault()
+            .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+        ctx.accounts.auction.base_lots_to_atoms(claimable_amount),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::program_accounts::*;
+use crate::token_interface::{self, CloseAccount};
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
+    // Technically doesn't need to be a signer for this function
+    #[account(mut)]
+    pub auctioneer: SystemAccount<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut,
+        close = auctioneer,
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    // AOB Accounts, these should already have been closed by close_aob_accounts
+    /// CHECK: Only the lamports are checked
+    #[account(address = auction.event_queue)]
+    pub event_queue: UncheckedAccount<'info>,
+    /// CHECK: Only the lamports are checked
+    #[account(address = auction.bids)]
+    pub bids: UncheckedAccount<'info>,
+    /// CHECK: Only the lamports are checked
+    #[account(address = auction.asks)]
+    pub asks: UncheckedAccount<'info>,
+    // Token Accounts
+    // The mints are writable so token-2022 can harvest withheld transfer fees to them
+    /// CHECK: The auction's quote mint
+    #[account(
+        address = auction.quote_mint,
+        mut
+    )]
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
+    #[account(
+        address = auction.base_mint,
+        mut
+    )]
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.quote_vault,
+        mut
+    )]
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.base_vault,
+        mut
+    )]
+    pub base_vault: UncheckedAccount<'info>,
+    // Programs
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
+}
+
+impl CloseAuction<'_> {
+    // close_aob_accounts can only run once the auction is over, so closed AOB
+    // accounts stand in for is_auction_over here
+    pub fn access_control(&self) -> Result<()> {
+        if self.event_queue.lamports() > 0
+            || self.bids.lamports() > 0
+            || self.asks.lamports() > 0
+        {
+            return Err(error!(CustomErrors::AobAccountsNotClosed));
+        }
+        if self.auction.num_open_orders > 0 {
+            return Err(error!(CustomErrors::OpenOrdersNotSettled));
+        }
+        // Anything left over should be withdrawn or swept first
+        if token_interface::token_account(&self.quote_vault)?.amount > 0
+            || token_interface::token_account(&self.base_vault)?.amount > 0
+        {
+            return Err(error!(CustomErrors::VaultsNotEmpty));
+        }
+        Ok(())
+    }
+}
+
+impl<'info> CloseAuction<'info> {
+    pub fn close_vault(
+        &self,
+        vault: &UncheckedAccount<'info>,
+        mint: &UncheckedAccount<'info>,
+        token_program: &UncheckedAccount<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let program = token_program.to_account_info();
+        let accounts = CloseAccount {
+            account: vault.to_account_info(),
+            mint: mint.to_account_info(),
+            destination: self.auctioneer.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+    token_interface::close_account(
+        ctx.accounts
+            .close_vault(
+                &ctx.accounts.quote_vault,
+                &ctx.accounts.quote_mint,
+                &ctx.accounts.quote_token_program,
+            )
+            .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+    )?;
+    token_interface::close_account(
+        ctx.accounts
+            .close_vault(
+                &ctx.accounts.base_vault,
+                &ctx.accounts.base_mint,
+                &ctx.accounts.base_token_program,
+            )
+            .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+    )?;
+
+    emit!(AuctionClosed {
+        auction: ctx.accounts.auction.key(),
+        authority: ctx.accounts.auction.authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+
+// The auction account might already be closed, so the auction id and
+// auctioneer are passed in to derive the addresses
+#[derive(Accounts)]
+#[instruction(auction_id: [u8; 10], auctioneer: Pubkey)]
+pub struct CloseOrderHistory<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    // Program Accounts
+    /// CHECK: Only the lamports are checked
+    #[account(
+        seeds = [user.key().as_ref(), OPEN_ORDERS.as_bytes(), &auction_id, auctioneer.as_ref()],
+        bump,
+    )]
+    pub open_orders: UncheckedAccount<'info>,
+    #[account(
+        seeds = [user.key().as_ref(), ORDER_HISTORY.as_bytes(), &auction_id, auctioneer.as_ref()],
+        bump = order_history.bump,
+        mut,
+        close = user,
+    )]
+    pub order_history: Account<'info, OrderHistory>,
+}
+
+impl CloseOrderHistory<'_> {
+    // Settling writes to the order history, so it has to happen first
+    pub fn access_control(&self) -> Result<()> {
+        if self.open_orders.lamports() > 0 {
+            return Err(error!(CustomErrors::OpenOrdersNotSettled));
+        }
+        Ok(())
+    }
+}
+
+pub fn close_order_history(
+    _ctx: Context<CloseOrderHistory>,
+    _auction_id: [u8; 10],
+    _auctioneer: Pubkey,
+) -> Result<()> {
+    Ok(())
+}
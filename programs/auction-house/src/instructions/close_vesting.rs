@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+
+// The auction account might already be closed, so the auction id and
+// auctioneer are passed in to derive the addresses
+#[derive(Accounts)]
+#[instruction(auction_id: [u8; 10], auctioneer: Pubkey)]
+pub struct CloseVesting<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    // Program Accounts
+    /// CHECK: Only the lamports are checked
+    #[account(
+        seeds = [user.key().as_ref(), OPEN_ORDERS.as_bytes(), &auction_id, auctioneer.as_ref()],
+        bump,
+    )]
+    pub open_orders: UncheckedAccount<'info>,
+    #[account(
+        seeds = [user.key().as_ref(), VESTING.as_bytes(), &auction_id, auctioneer.as_ref()],
+        bump = vesting.bump,
+        mut,
+        close = user,
+    )]
+    pub vesting: Account<'info, Vesting>,
+}
+
+impl CloseVesting<'_> {
+    // Settling adds the purchased base to the vesting account, so it has to happen first
+    pub fn access_control(&self) -> Result<()> {
+        if self.open_orders.lamports() > 0 {
+            return Err(error!(CustomErrors::OpenOrdersNotSettled));
+        }
+        if self.vesting.base_amount_claimed < self.vesting.base_amount_vesting {
+            return Err(error!(CustomErrors::VestedTokensNotClaimed));
+        }
+        Ok(())
+    }
+}
+
+pub fn close_vesting(
+    _ctx: Context<CloseVesting>,
+    _auction_id: [u8; 10],
+    _auctioneer: Pubkey,
+) -> Result<()> {
+    Ok(())
+}
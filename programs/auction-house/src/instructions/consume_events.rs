@@ -3,12 +3,12 @@ use anchor_lang::prelude::*;
 use agnostic_orderbook::state::{
     Event, EventQueue, EventQueueHeader, Side as AobSide, EVENT_QUEUE_HEADER_LEN,
 };
-use bonfida_utils::fp_math::fp32_mul;
 
 use std::convert::TryInto;
 
 use crate::consts::*;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
 
 #[derive(Accounts)]
@@ -53,14 +53,14 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
             // hereafter refers to the maker as the user
             Event::Fill {
                 taker_side,
-                maker_order_id: _,
+                maker_order_id,
                 quote_size,
                 base_size,
                 maker_callback_info,
                 taker_callback_info: _,
             } => {
                 let user_side = taker_side.opposite();
-                let user_pubkey = Pubkey::new_from_array(maker_callback_info.try_into().unwrap());
+                let user_pubkey = Pubkey::new_from_array(maker_callback_info[..32].try_into().unwrap());
                 let user_account_info = ctx
                     .remaining_accounts
                     .iter()
@@ -99,6 +99,23 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
                             .unwrap();
                     }
                 }
+                // A missing record only leaves a gap in the order history, the
+                // event still has to be consumed or the queue would be stuck
+                match user_open_orders.find_order_record(&maker_order_id) {
+                    Ok(record) => {
+                        record.base_filled = record.base_filled.checked_add(base_size).unwrap();
+                        record.quote_filled = record.quote_filled.checked_add(quote_size).unwrap();
+                    }
+                    Err(_) => msg!("No order record for order {}, skipping it", maker_order_id),
+                }
+                emit!(OrderFilled {
+                    auction: ctx.accounts.auction.key(),
+                    open_orders: user_pubkey,
+                    side: user_open_orders.side,
+                    order_id: maker_order_id,
+                    base_size,
+                    quote_size,
+                });
                 user_open_orders.exit(ctx.program_id)?;
             }
             Event::Out {
@@ -109,7 +126,7 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
                 delete: _,
             } => {
                 let user_side = side;
-                let user_pubkey = Pubkey::new_from_array(callback_info.try_into().unwrap());
+                let user_pubkey = Pubkey::new_from_array(callback_info[..32].try_into().unwrap());
                 let user_account_info = ctx
                     .remaining_accounts
                     .iter()
@@ -126,7 +143,7 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
                 if AobSide::from(user_open_orders.side) != user_side {
                     return Err(error!(CustomErrors::UserSideDiffFromEventSide));
                 }
-                match user_side {
+                let quote_refund = match user_side {
                     AobSide::Ask => {
                         user_open_orders.base_token_free = user_open_orders
                             .base_token_free
@@ -136,10 +153,13 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
                             .base_token_locked
                             .checked_sub(base_size)
                             .unwrap();
+                        0
                     }
                     AobSide::Bid => {
-                        let price = (order_id >> 64) as u64;
-                        let quote_size = fp32_mul(base_size, price).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
+                        // Match orders works out the exact refund, rather than
+                        // converting quote to base and back again
+                        let quote_size =
+                            u64::from_le_bytes(callback_info[32..40].try_into().unwrap());
                         user_open_orders.quote_token_free = user_open_orders
                             .quote_token_free
                             .checked_add(quote_size)
@@ -148,8 +168,30 @@ pub fn consume_events(ctx: Context<ConsumeEvents>, limit: u16, allow_no_op: bool
                             .quote_token_locked
                             .checked_sub(quote_size)
                             .unwrap();
+                        quote_size
                     }
+                };
+                match user_open_orders.find_order_record(&order_id) {
+                    Ok(record) => match user_side {
+                        AobSide::Ask => {
+                            record.base_refunded =
+                                record.base_refunded.checked_add(base_size).unwrap();
+                        }
+                        AobSide::Bid => {
+                            record.quote_refunded =
+                                record.quote_refunded.checked_add(quote_refund).unwrap();
+                        }
+                    },
+                    Err(_) => msg!("No order record for order {}, skipping it", order_id),
                 }
+                emit!(OrderOut {
+                    auction: ctx.accounts.auction.key(),
+                    open_orders: user_pubkey,
+                    side: user_open_orders.side,
+                    order_id,
+                    base_size,
+                    quote_refund,
+                });
 
                 let order_idx = user_open_orders.find_order_index(&order_id)?;
                 user_open_orders.orders.remove(order_idx);
@@ -5,6 +5,7 @@ use anchor_lang::prelude::*;
 use agnostic_orderbook::orderbook::OrderBookState;
 use agnostic_orderbook::state::{EventQueue, EventQueueHeader, EVENT_QUEUE_HEADER_LEN};
 
+use curve25519_dalek::scalar::Scalar;
 use xsalsa20poly1305::{
     aead::{Aead, NewAead},
     Nonce, XSalsa20Poly1305,
@@ -12,7 +13,9 @@ use xsalsa20poly1305::{
 
 use crate::access_controls::*;
 use crate::consts::*;
+use crate::encryption::nacl_shared_key;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
 use crate::types::*;
 
@@ -68,117 +71,210 @@ impl DecryptOrder<'_> {
             return Err(error!(CustomErrors::DecryptionPhaseNotActive));
         };
         encrypted_orders_only(&auction, &open_orders)?;
+        if auction.is_commit_reveal {
+            return Err(error!(CustomErrors::NotAvailableForCommitReveal));
+        }
+        if !has_enough_key_shares(&auction) {
+            return Err(error!(CustomErrors::NotEnoughKeyShares));
+        }
 
         Ok(())
     }
 }
 
+/// Committee auctions ignore `shared_key`, it's derived from the committee's
+/// rebuilt secret key and the user's public key instead
 pub fn decrypt_order(ctx: Context<DecryptOrder>, shared_key: Vec<u8>) -> Result<()> {
+    let shared_key = match ctx.accounts.auction.committee.is_empty() {
+        true => shared_key,
+        false => nacl_shared_key(
+            &Scalar::from_bits(ctx.accounts.auction.committee_secret_key),
+            &ctx.accounts.open_orders.nacl_pubkey,
+        )?
+        .to_vec(),
+    };
+    decrypt_and_post_orders(
+        &ctx.accounts.auction,
+        &mut ctx.accounts.open_orders,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &shared_key,
+    )
+}
+
+/// Decrypts every encrypted order with the shared key and posts it to the
+/// book. Used by both the auctioneer and the user revealing their own orders,
+/// since either side of the key exchange can derive the shared key.
+pub fn decrypt_and_post_orders<'info>(
+    auction: &Auction,
+    open_orders: &mut OpenOrders,
+    event_queue_account: &AccountInfo<'info>,
+    bids: &AccountInfo<'info>,
+    asks: &AccountInfo<'info>,
+    shared_key: &[u8],
+) -> Result<()> {
     // Load up all the AOB accounts
-    let mut order_book = OrderBookState::new_safe(
-        &ctx.accounts.bids.to_account_info(),
-        &ctx.accounts.asks.to_account_info(),
-        CALLBACK_INFO_LEN,
-        CALLBACK_ID_LEN,
-    )?;
+    let mut order_book = OrderBookState::new_safe(bids, asks, CALLBACK_INFO_LEN, CALLBACK_ID_LEN)?;
     let header = {
         let mut event_queue_data: &[u8] =
-            &ctx.accounts.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+            &event_queue_account.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
         EventQueueHeader::deserialize(&mut event_queue_data)
             .unwrap()
             .check()?
     };
-    let mut event_queue = EventQueue::new_safe(
-        header,
-        &ctx.accounts.event_queue.to_account_info(),
-        CALLBACK_INFO_LEN,
-    )?;
+    let mut event_queue = EventQueue::new_safe(header, event_queue_account, CALLBACK_INFO_LEN)?;
 
-    let key = xsalsa20poly1305::Key::from_slice(shared_key.as_slice());
+    // from_slice panics on the wrong lengths
+    if shared_key.len() != 32 {
+        return Err(error!(CustomErrors::InvalidSharedKey));
+    }
+    let key = xsalsa20poly1305::Key::from_slice(shared_key);
     let cypher = XSalsa20Poly1305::new(key);
 
-    let open_orders = &mut *ctx.accounts.open_orders;
+    let num_orders = open_orders.encrypted_orders.len() as u8;
+    let mut total_base_qty: u64 = 0;
+    let mut total_quote_qty: u64 = 0;
     for encrypted_order in open_orders.encrypted_orders.clone().iter() {
+        if encrypted_order.nonce.len() != 24 {
+            return Err(error!(CustomErrors::InvalidSharedKey));
+        }
         let nonce = Nonce::from_slice(encrypted_order.nonce.as_slice());
         // TODO Make sure that we're encrypting price and qty correctly on client side
         let price_and_quantity = cypher
             .decrypt(nonce, encrypted_order.cipher_text.as_slice())
             .map_err(|_| error!(CustomErrors::InvalidSharedKey))?;
-        let limit_price = u64::from_le_bytes(price_and_quantity[0..8].try_into().unwrap());
-        let max_base_qty = u64::from_le_bytes(price_and_quantity[8..16].try_into().unwrap());
         // If any order triggers an error, then none of the orders will be processed.
-        validate_price_and_qty(
-            &ctx.accounts.auction.clone().into_inner(),
-            limit_price,
-            max_base_qty,
+        let (base_qty, quote_qty) = post_revealed_order(
+            auction,
+            open_orders,
+            &mut order_book,
+            &mut event_queue,
+            encrypted_order.token_qty,
+            &price_and_quantity,
         )?;
-        // Place a new order
-        let params = open_orders.new_order_params(limit_price, max_base_qty);
-        let order_summary = order_book
-            .new_order(
-                params,
-                &mut event_queue,
-                ctx.accounts.auction.min_base_order_size,
-            )
-            .unwrap();
-        // Make sure the order has enough tokens.
-        // If the order is for less than token_qty then move that amount to token_free balance.
-        match open_orders.side {
-            Side::Ask => {
-                msg!("max base qty: {}, limit price in FP32: {}", max_base_qty, limit_price);
-                msg!("order summary {:?}", order_summary);
-                if encrypted_order.token_qty < order_summary.total_base_qty {
-                    return Err(error!(CustomErrors::InsufficientTokensForOrder));
-                }
-                let remaining_tokens = encrypted_order
-                    .token_qty
-                    .checked_sub(order_summary.total_base_qty)
-                    .unwrap();
-                if remaining_tokens > 0 {
-                    open_orders.base_token_free = open_orders
-                        .base_token_free
-                        .checked_add(remaining_tokens)
-                        .unwrap();
-                    open_orders.base_token_locked = open_orders
-                        .base_token_locked
-                        .checked_sub(remaining_tokens)
-                        .unwrap();
-                }
-            }
-            Side::Bid => {
-                msg!("max base qty: {}, limit price in FP32: {}", max_base_qty, limit_price);
-                msg!("order summary {:?}", order_summary);
-                if encrypted_order.token_qty < order_summary.total_quote_qty {
-                    return Err(error!(CustomErrors::InsufficientTokensForOrder));
-                }
-                let remaining_tokens = encrypted_order
-                    .token_qty
-                    .checked_sub(order_summary.total_quote_qty)
-                    .unwrap();
-                if remaining_tokens > 0 {
-                    open_orders.quote_token_free = open_orders
-                        .quote_token_free
-                        .checked_add(remaining_tokens)
-                        .unwrap();
-                    open_orders.quote_token_locked = open_orders
-                        .quote_token_locked
-                        .checked_sub(remaining_tokens)
-                        .unwrap();
-                }
-            }
-        }
-
-        open_orders
-            .orders
-            .push(order_summary.posted_order_id.unwrap());
+        total_base_qty = total_base_qty.checked_add(base_qty).unwrap();
+        total_quote_qty = total_quote_qty.checked_add(quote_qty).unwrap();
     }
+    emit!(OrdersDecrypted {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        num_orders,
+        base_qty: total_base_qty,
+        quote_qty: total_quote_qty,
+    });
 
     open_orders.encrypted_orders = Vec::new();
     order_book.commit_changes();
-    let mut event_queue_header_data: &mut [u8] = &mut ctx.accounts.event_queue.data.borrow_mut();
+    let mut event_queue_header_data: &mut [u8] = &mut event_queue_account.data.borrow_mut();
     event_queue
         .header
         .serialize(&mut event_queue_header_data)
         .unwrap();
     Ok(())
 }
+
+/// Posts a decrypted or revealed order to the book. The plaintext is the limit
+/// price and quantity as little endian u64s, an optional 17th byte set to 1
+/// means the quantity is a quote budget instead of base. Returns the base and
+/// quote quantities of the posted order.
+pub fn post_revealed_order(
+    auction: &Auction,
+    open_orders: &mut OpenOrders,
+    order_book: &mut OrderBookState,
+    event_queue: &mut EventQueue,
+    token_qty: u64,
+    price_and_quantity: &[u8],
+) -> Result<(u64, u64)> {
+    let limit_price = u64::from_le_bytes(price_and_quantity[0..8].try_into().unwrap());
+    let (max_base_qty, max_quote_qty) = match price_and_quantity.get(16) {
+        Some(1) => {
+            if open_orders.side != Side::Bid {
+                return Err(error!(CustomErrors::QuoteBudgetOnlyForBids));
+            }
+            let max_quote_qty = u64::from_le_bytes(price_and_quantity[8..16].try_into().unwrap());
+            (
+                base_qty_from_quote_budget(limit_price, max_quote_qty)?,
+                max_quote_qty,
+            )
+        }
+        _ => (
+            u64::from_le_bytes(price_and_quantity[8..16].try_into().unwrap()),
+            u64::MAX,
+        ),
+    };
+    validate_price_and_qty(auction, limit_price, max_base_qty)?;
+    // Place a new order
+    let params = open_orders.new_order_params(limit_price, max_base_qty, max_quote_qty);
+    let order_summary = order_book
+        .new_order(params, event_queue, auction.min_base_order_size)
+        .unwrap();
+    // Make sure the order has enough tokens.
+    // If the order is for less than token_qty then move that amount to token_free balance.
+    match open_orders.side {
+        Side::Ask => {
+            if token_qty < order_summary.total_base_qty {
+                return Err(error!(CustomErrors::InsufficientTokensForOrder));
+            }
+            let remaining_tokens = token_qty
+                .checked_sub(order_summary.total_base_qty)
+                .unwrap();
+            if remaining_tokens > 0 {
+                open_orders.base_token_free = open_orders
+                    .base_token_free
+                    .checked_add(remaining_tokens)
+                    .unwrap();
+                open_orders.base_token_locked = open_orders
+                    .base_token_locked
+                    .checked_sub(remaining_tokens)
+                    .unwrap();
+            }
+        }
+        Side::Bid => {
+            let total_quote_qty = bid_quote_qty(order_summary.total_base_qty, limit_price)?;
+            if token_qty < total_quote_qty {
+                return Err(error!(CustomErrors::InsufficientTokensForOrder));
+            }
+            let remaining_tokens = token_qty.checked_sub(total_quote_qty).unwrap();
+            if remaining_tokens > 0 {
+                open_orders.quote_token_free = open_orders
+                    .quote_token_free
+                    .checked_add(remaining_tokens)
+                    .unwrap();
+                open_orders.quote_token_locked = open_orders
+                    .quote_token_locked
+                    .checked_sub(remaining_tokens)
+                    .unwrap();
+            }
+        }
+    }
+
+    has_space_under_max_base_per_user(auction, open_orders, order_summary.total_base_qty)?;
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_add(order_summary.total_base_qty)
+        .unwrap();
+
+    open_orders
+        .orders
+        .push(order_summary.posted_order_id.unwrap());
+    open_orders.order_records.push(OrderRecord::new(
+        order_summary.posted_order_id.unwrap(),
+        limit_price,
+        order_summary.total_base_qty,
+    ));
+
+    let quote_qty = bid_quote_qty(order_summary.total_base_qty, limit_price)?;
+    emit!(OrderPlaced {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id: order_summary.posted_order_id.unwrap(),
+        limit_price,
+        base_qty: order_summary.total_base_qty,
+        quote_qty,
+    });
+
+    Ok((order_summary.total_base_qty, quote_qty))
+}
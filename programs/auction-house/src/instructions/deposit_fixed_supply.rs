@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+use crate::token_interface::{
+    amount_with_transfer_fee, get_associated_token_address, transfer_checked, TransferChecked,
+};
+
+#[derive(Accounts)]
+pub struct DepositFixedSupply<'info> {
+    pub auctioneer: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    // Token accounts
+    /// CHECK: The auction's base mint
+    #[account(address = auction.base_mint)]
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The auctioneer's associated token account, the token program checks the rest
+    #[account(
+        address = get_associated_token_address(&auctioneer.key(), &auction.base_mint, &auction.base_token_program),
+        mut
+    )]
+    pub auctioneer_base: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.base_vault,
+        mut
+    )]
+    pub base_vault: UncheckedAccount<'info>,
+    // Programs
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
+}
+
+impl DepositFixedSupply<'_> {
+    pub fn access_control(&self) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+
+        if !auction.is_fixed_supply() {
+            return Err(error!(CustomErrors::NotFixedSupplyAuction));
+        }
+        if auction.has_deposited_fixed_supply {
+            return Err(error!(CustomErrors::FixedSupplyAlreadyDeposited));
+        }
+        if auction.is_cancelled {
+            return Err(error!(CustomErrors::AuctionCancelled));
+        }
+        // Bidders should be able to see the supply before the order phase is over
+        if auction.end_order_phase < clock.unix_timestamp {
+            return Err(error!(CustomErrors::OrderPhaseIsOver));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> DepositFixedSupply<'info> {
+    pub fn transfer_auctioneer_base(
+        &self,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.auctioneer_base.to_account_info(),
+            mint: self.base_mint.to_account_info(),
+            to: self.base_vault.to_account_info(),
+            authority: self.auctioneer.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn deposit_fixed_supply(ctx: Context<DepositFixedSupply>) -> Result<()> {
+    transfer_checked(
+        ctx.accounts.transfer_auctioneer_base(),
+        amount_with_transfer_fee(
+            &ctx.accounts.base_mint,
+            ctx.accounts
+                .auction
+                .base_lots_to_atoms(ctx.accounts.auction.fixed_base_supply),
+        )?,
+    )?;
+    ctx.accounts.auction.has_deposited_fixed_supply = true;
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
-use anchor_spl::token::{Mint, Token, TokenAccount};
-
+use crate::access_controls::*;
 use crate::consts::*;
-use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
+use crate::token_interface;
 use crate::types::*;
 
 use agnostic_orderbook::critbit::Slab;
@@ -24,7 +24,8 @@ pub struct InitAuction<'info> {
         init,
         seeds = [AUCTION.as_bytes(), &args.auction_id, auctioneer.key().as_ref()],
         bump,
-        space = 1000,
+        // Plus a pubkey, a public share and a key share for each committee member
+        space = 1200 + 97 * args.committee.len(),
         payer = auctioneer,
     )]
     pub auction: Box<Account<'info, Auction>>,
@@ -38,58 +39,41 @@ pub struct InitAuction<'info> {
     #[account(zero, owner = crate::ID)]
     pub asks: UncheckedAccount<'info>,
     // Token vaults
-    pub quote_mint: Account<'info, Mint>,
-    pub base_mint: Account<'info, Mint>,
+    /// CHECK: Either token program's mint, checked in validate_args
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: Either token program's mint, checked in validate_args
+    pub base_mint: UncheckedAccount<'info>,
+    // The vaults are created in the handler since Anchor's token init only
+    // supports the original token program. The auction account signs for them
+    /// CHECK: Created and initialised in the handler
     #[account(
-        init,
-        token::mint = quote_mint,
-        token::authority = auction, // It should probably be the auction account, since it will sign
         seeds = [QUOTE_VAULT.as_bytes(), &args.auction_id, auctioneer.key().as_ref()],
         bump,
-        payer = auctioneer,
+        mut
     )]
-    pub quote_vault: Account<'info, TokenAccount>,
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created and initialised in the handler
     #[account(
-        init,
-        token::mint = base_mint,
-        token::authority = auction, // It should probably be the auction account, since it will sign
         seeds = [BASE_VAULT.as_bytes(), &args.auction_id, auctioneer.key().as_ref()],
         bump,
-        payer = auctioneer,
+        mut
     )]
-    pub base_vault: Account<'info, TokenAccount>,
-    // Sysvars
-    pub rent: Sysvar<'info, Rent>,
+    pub base_vault: UncheckedAccount<'info>,
     // Programs
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = *quote_mint.owner)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = *base_mint.owner)]
+    pub base_token_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
 impl InitAuction<'_> {
     pub fn validate_args(&self, args: &InitAuctionArgs) -> Result<()> {
-        let clock = Clock::get()?;
-        // Orders phase ends before it starts
-        if args.end_order_phase <= args.start_order_phase {
-            return Err(error!(CustomErrors::InvalidStartTimes));
-        }
-        // Orders phase should end in the future
-        if args.end_order_phase <= clock.unix_timestamp {
-            return Err(error!(CustomErrors::InvalidEndTimes));
-        }
-        // Decryption phase should end at or after the end of the order phase
-        if args.end_decryption_phase < args.end_order_phase {
-            return Err(error!(CustomErrors::InvalidDecryptionEndTime));
-        }
-        if args.min_base_order_size == 0 {
-            return Err(error!(CustomErrors::InvalidMinBaseOrderSize));
-        }
-        if args.tick_size == 0 {
-            return Err(error!(CustomErrors::InvalidTickSize));
-        }
-
-        if self.quote_mint.decimals != self.base_mint.decimals {
-            return Err(error!(CustomErrors::IncompatibleMintDecimals));
-        }
+        validate_auction_args(Clock::get()?, args)?;
+        token_interface::validate_mint(&self.quote_mint)?;
+        token_interface::validate_mint(&self.base_mint)?;
 
         Ok(())
     }
@@ -97,6 +81,36 @@ impl InitAuction<'_> {
 
 ///
 pub fn init_auction(ctx: Context<InitAuction>, args: &InitAuctionArgs) -> Result<()> {
+    let auctioneer_key = ctx.accounts.auctioneer.key();
+    token_interface::init_vault(
+        &ctx.accounts.auctioneer.to_account_info(),
+        &ctx.accounts.quote_vault.to_account_info(),
+        &ctx.accounts.quote_mint.to_account_info(),
+        &ctx.accounts.auction.to_account_info(),
+        &ctx.accounts.quote_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[
+            QUOTE_VAULT.as_bytes(),
+            &args.auction_id,
+            auctioneer_key.as_ref(),
+            &[*ctx.bumps.get("quote_vault").unwrap()],
+        ],
+    )?;
+    token_interface::init_vault(
+        &ctx.accounts.auctioneer.to_account_info(),
+        &ctx.accounts.base_vault.to_account_info(),
+        &ctx.accounts.base_mint.to_account_info(),
+        &ctx.accounts.auction.to_account_info(),
+        &ctx.accounts.base_token_program.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[
+            BASE_VAULT.as_bytes(),
+            &args.auction_id,
+            auctioneer_key.as_ref(),
+            &[*ctx.bumps.get("base_vault").unwrap()],
+        ],
+    )?;
+
     ctx.accounts.auction.set_inner(Auction {
         bump: *ctx.bumps.get("auction").unwrap(),
         bumps: AobBumps {
@@ -108,9 +122,23 @@ pub fn init_auction(ctx: Context<InitAuction>, args: &InitAuctionArgs) -> Result
         start_order_phase: args.start_order_phase,
         end_order_phase: args.end_order_phase,
         end_decryption_phase: args.end_decryption_phase,
+        soft_close_window: args.soft_close_window,
+        soft_close_extension: args.soft_close_extension,
+        max_soft_close_extension: args.max_soft_close_extension,
+        total_soft_close_extension: 0,
         are_asks_encrypted: args.are_asks_encrypted,
         are_bids_encrypted: args.are_bids_encrypted,
         nacl_pubkey: args.nacl_pubkey.clone(),
+        is_commit_reveal: args.is_commit_reveal,
+        unrevealed_penalty_bps: args.unrevealed_penalty_bps,
+        committee: args.committee.clone(),
+        committee_threshold: args.committee_threshold,
+        committee_public_shares: args.committee_public_shares.clone(),
+        key_shares: Vec::new(),
+        committee_secret_key: [0; 32],
+        final_price_type: args.final_price_type,
+        fill_allocation_type: args.fill_allocation_type,
+        is_pay_as_bid: args.is_pay_as_bid,
         // Order book stuff
         event_queue: ctx.accounts.event_queue.key(),
         bids: ctx.accounts.bids.key(),
@@ -119,21 +147,52 @@ pub fn init_auction(ctx: Context<InitAuction>, args: &InitAuctionArgs) -> Result
         base_mint: ctx.accounts.base_mint.key(),
         quote_vault: ctx.accounts.quote_vault.key(),
         base_vault: ctx.accounts.base_vault.key(),
+        quote_token_program: ctx.accounts.quote_token_program.key(),
+        base_token_program: ctx.accounts.base_token_program.key(),
+        base_lot_size: args.base_lot_size,
+        quote_lot_size: args.quote_lot_size,
         min_base_order_size: args.min_base_order_size,
         tick_size: args.tick_size,
+        max_base_per_user: args.max_base_per_user,
+        allowlist_root: args.allowlist_root,
+        reserve_price: args.reserve_price,
+        reserve_price_commitment: args.reserve_price_commitment,
+        min_base_matched: args.min_base_matched,
+        min_quote_raised: args.min_quote_raised,
+        fixed_base_supply: args.fixed_base_supply,
+        has_deposited_fixed_supply: false,
+        vesting_cliff: args.vesting_cliff,
+        vesting_duration: args.vesting_duration,
         // Everything else defaults to 0
         current_bid_key: 0,
         current_ask_key: 0,
         current_bid_quantity_filled: 0,
         current_ask_quantity_filled: 0,
         total_quantity_filled_so_far: 0,
+        current_bid_level_quantity: 0,
+        current_ask_level_quantity: 0,
+        filled_bid_levels_quantity: 0,
+        filled_ask_levels_quantity: 0,
+        has_crossed: false,
+        is_bid_level_complete: false,
+        is_ask_level_complete: false,
+        first_losing_bid_price: None,
         has_found_clearing_price: false,
+        has_failed: false,
         total_quantity_matched: 0,
         remaining_bid_fills: 0,
         remaining_ask_fills: 0,
         final_bid_price: 0,
         final_ask_price: 0,
         clearing_price: 0,
+        marginal_bid_quantity: 0,
+        marginal_ask_quantity: 0,
+        base_sold: 0,
+        quote_proceeds: 0,
+        has_withdrawn_proceeds: false,
+        is_cancelled: false,
+        num_open_orders: 0,
+        base_vesting_unclaimed: 0,
         ask_search_stack_depth: 0,
         ask_search_stack_values: [0; 32],
         bid_search_stack_depth: 0,
@@ -154,5 +213,17 @@ pub fn init_auction(ctx: Context<InitAuction>, args: &InitAuctionArgs) -> Result
         CALLBACK_INFO_LEN,
     );
 
+    let auction = &ctx.accounts.auction;
+    emit!(AuctionCreated {
+        auction: auction.key(),
+        authority: auction.authority,
+        auction_id: auction.auction_id,
+        quote_mint: auction.quote_mint,
+        base_mint: auction.base_mint,
+        start_order_phase: auction.start_order_phase,
+        end_order_phase: auction.end_order_phase,
+        end_decryption_phase: auction.end_decryption_phase,
+    });
+
     Ok(())
 }
@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 
-use anchor_spl::token::{Mint, TokenAccount};
-
 use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
 use crate::program_accounts::*;
+use crate::token_interface::get_associated_token_address;
 use crate::types::*;
+use crate::utils::create_pda_account;
 
 // Flexible on design decisions such as:
 // should we check that the user has the associated token accounts that will
@@ -20,6 +20,7 @@ pub struct InitOpenOrders<'info> {
     #[account(
         seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bump,
+        mut
     )]
     pub auction: Box<Account<'info, Auction>>,
     #[account(
@@ -27,13 +28,15 @@ pub struct InitOpenOrders<'info> {
         seeds = [user.key().as_ref(), OPEN_ORDERS.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump,
         space = {
-            let mut this_space: usize = 172;
+            let mut this_space: usize = 192;
             if (auction.are_asks_encrypted && side == Side::Ask) || (auction.are_bids_encrypted && side == Side::Bid) {
                 msg!("max orders {}", max_orders);
                 this_space = this_space.checked_add(100_usize.checked_mul(max_orders as usize).unwrap()).unwrap();
             } else {
                 this_space = this_space.checked_add(16_usize.checked_mul(max_orders as usize).unwrap()).unwrap();
             }
+            // Plus a 64 byte record for each order
+            this_space = this_space.checked_add(64_usize.checked_mul(max_orders as usize).unwrap()).unwrap();
             msg!("space for this open orders {}", this_space);
             this_space
         },
@@ -44,32 +47,42 @@ pub struct InitOpenOrders<'info> {
         init,
         seeds = [user.key().as_ref(), ORDER_HISTORY.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump,
-        space = 58,
+        space = ORDER_HISTORY_SPACE,
         payer = user,
     )]
     pub order_history: Account<'info, OrderHistory>,
+    /// CHECK: Only created if the user's purchased base will vest
+    #[account(
+        seeds = [user.key().as_ref(), VESTING.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump,
+        mut
+    )]
+    pub vesting: UncheckedAccount<'info>,
     // Token accounts
-    #[account(address = auction.quote_mint)]
-    pub quote_mint: Account<'info, Mint>,
-    #[account(address = auction.base_mint)]
-    pub base_mint: Account<'info, Mint>,
+    /// CHECK: Has to be the user's existing associated token account
     #[account(
-        associated_token::mint = quote_mint,
-        associated_token::authority = user,
-        // user_quote.owner == user.key(), // TODO compile errors here for some reason?
+        address = get_associated_token_address(&user.key(), &auction.quote_mint, &auction.quote_token_program),
+        owner = auction.quote_token_program,
     )]
-    pub user_quote: Account<'info, TokenAccount>,
+    pub user_quote: UncheckedAccount<'info>,
+    /// CHECK: Has to be the user's existing associated token account
     #[account(
-        associated_token::mint = base_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.base_mint, &auction.base_token_program),
+        owner = auction.base_token_program,
     )]
-    pub user_base: Account<'info, TokenAccount>,
+    pub user_base: UncheckedAccount<'info>,
     // Programs
     pub system_program: Program<'info, System>,
 }
 
 impl InitOpenOrders<'_> {
-    pub fn access_control(&self, max_orders: u8) -> Result<()> {
+    pub fn access_control(
+        &self,
+        side: Side,
+        max_orders: u8,
+        allowlist_proof: &[[u8; 32]],
+        max_base_allocation: u64,
+    ) -> Result<()> {
         let clock = Clock::get()?;
         let auction = self.auction.clone().into_inner();
 
@@ -80,11 +93,34 @@ impl InitOpenOrders<'_> {
         if !(1..=6).contains(&max_orders) {
             return Err(error!(CustomErrors::MaxOrdersValueIsInvalid));
         }
+        if auction.is_fixed_supply() && side == Side::Ask {
+            return Err(error!(CustomErrors::AskOrdersNotAllowed));
+        }
+        if auction.allowlist_root != [0; 32]
+            && !is_on_allowlist(
+                &auction.allowlist_root,
+                allowlist_proof,
+                &self.user.key(),
+                max_base_allocation,
+            )
+        {
+            return Err(error!(CustomErrors::InvalidAllowlistProof));
+        }
         Ok(())
     }
 }
 
-pub fn init_open_orders(ctx: Context<InitOpenOrders>, side: Side, max_orders: u8) -> Result<()> {
+pub fn init_open_orders(
+    ctx: Context<InitOpenOrders>,
+    side: Side,
+    max_orders: u8,
+    max_base_allocation: u64,
+) -> Result<()> {
+    // Allocations only come from the allowlist
+    let max_base_allocation = match ctx.accounts.auction.allowlist_root != [0; 32] {
+        true => max_base_allocation,
+        false => 0,
+    };
     ctx.accounts.open_orders.set_inner(OpenOrders {
         bump: *ctx.bumps.get("open_orders").unwrap(),
         authority: ctx.accounts.user.key(),
@@ -101,6 +137,10 @@ pub fn init_open_orders(ctx: Context<InitOpenOrders>, side: Side, max_orders: u8
         base_token_free: 0,
         num_orders: 0,
         orders: Vec::new(),
+        order_records: Vec::new(),
+        max_base_allocation,
+        base_qty_ordered: 0,
+        base_qty_filled: 0,
     });
 
     ctx.accounts.order_history.set_inner(OrderHistory {
@@ -109,6 +149,46 @@ pub fn init_open_orders(ctx: Context<InitOpenOrders>, side: Side, max_orders: u8
         side,
         quote_amount_returned: 0,
         base_amount_returned: 0,
+        clearing_price: 0,
+        orders: Vec::new(),
     });
+    ctx.accounts.auction.num_open_orders =
+        ctx.accounts.auction.num_open_orders.checked_add(1).unwrap();
+
+    if ctx.accounts.auction.needs_vesting_account(side) {
+        ctx.accounts
+            .init_vesting(*ctx.bumps.get("vesting").unwrap())?;
+    }
     Ok(())
 }
+
+impl InitOpenOrders<'_> {
+    // Anchor can't init an account conditionally, so this does what init would
+    fn init_vesting(&self, bump: u8) -> Result<()> {
+        let user_key = self.user.key();
+        create_pda_account(
+            &self.user.to_account_info(),
+            &self.vesting.to_account_info(),
+            VESTING_SPACE,
+            &crate::ID,
+            &self.system_program.to_account_info(),
+            &[
+                user_key.as_ref(),
+                VESTING.as_bytes(),
+                &self.auction.auction_id,
+                self.auction.authority.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let vesting = Vesting {
+            bump,
+            auction: self.auction.key(),
+            base_amount_vesting: 0,
+            base_amount_claimed: 0,
+        };
+        let mut vesting_data = self.vesting.try_borrow_mut_data()?;
+        vesting.try_serialize(&mut &mut vesting_data[..])?;
+        Ok(())
+    }
+}
@@ -1,17 +1,20 @@
 use std::cmp;
+use std::convert::TryInto;
 
 use anchor_lang::prelude::*;
 
+use agnostic_orderbook::critbit::LeafNode;
 use agnostic_orderbook::orderbook::OrderBookState;
 use agnostic_orderbook::state::{
     Event, EventQueue, EventQueueHeader, Side as AobSide, EVENT_QUEUE_HEADER_LEN,
 };
-use bonfida_utils::fp_math::{fp32_div, fp32_mul};
+use bonfida_utils::fp_math::fp32_mul;
 
 use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
 use crate::program_accounts::*;
+use crate::types::*;
 
 #[derive(Accounts)]
 pub struct MatchOrders<'info> {
@@ -94,6 +97,15 @@ pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
     } else {
         AobSide::Bid
     };
+    if side == AobSide::Ask && auction.remaining_bid_fills > 0 {
+        // Fills cut from capped bids that no later bid could take, the asks
+        // fill that much less so both sides stay balanced
+        auction.remaining_ask_fills = auction
+            .remaining_ask_fills
+            .checked_sub(auction.remaining_bid_fills)
+            .unwrap();
+        auction.remaining_bid_fills = 0;
+    }
 
     for _ in 0..limit {
         // bbo: best bid or offer
@@ -113,9 +125,19 @@ pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
         match side {
             AobSide::Ask => {
                 let mut fill_size: u64 = 0;
+                // Pay as bid asks receive their own price, so the spread between what
+                // the bids pay and what the asks receive is left over in the quote vault
+                let fill_price = match auction.is_pay_as_bid {
+                    true => cmp::max(bbo_node.price(), auction.reserve_price),
+                    false => auction.clearing_price,
+                };
                 if auction.remaining_ask_fills > 0 {
-                    fill_size = cmp::min(bbo_node.base_quantity, auction.remaining_ask_fills);
-                    let quote_size = fp32_mul(fill_size, auction.clearing_price).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
+                    fill_size = cmp::min(
+                        allocated_fill(auction, &bbo_node, side)?,
+                        auction.remaining_ask_fills,
+                    );
+                    // Rounded down, the bids pay rounded up
+                    let quote_size = fp32_mul(fill_size, fill_price).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
                     let order_fill = Event::Fill {
                         taker_side: side.opposite(),
                         maker_callback_info: order_book
@@ -154,9 +176,34 @@ pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
             }
             AobSide::Bid => {
                 let mut fill_size: u64 = 0;
+                let fill_price = match auction.is_pay_as_bid {
+                    true => bbo_node.price(),
+                    false => auction.clearing_price,
+                };
                 if auction.remaining_bid_fills > 0 {
-                    fill_size = cmp::min(bbo_node.base_quantity, auction.remaining_bid_fills);
-                    let quote_size = fp32_mul(fill_size, auction.clearing_price).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
+                    let allocated_size = cmp::min(
+                        allocated_fill(auction, &bbo_node, side)?,
+                        auction.remaining_bid_fills,
+                    );
+                    let callback_info = order_book
+                        .get_tree(side)
+                        .get_callback_info(bbo_node.callback_info_pt as usize)
+                        .to_owned();
+                    fill_size = capped_bid_fill(
+                        auction,
+                        ctx.remaining_accounts,
+                        &callback_info,
+                        allocated_size,
+                    )?;
+                    // Anything over the user's cap is refunded instead and stays in the
+                    // remaining fills, the marginal bids after this one share it out.
+                    // Bids already matched can't be filled any further.
+                    auction.remaining_bid_fills =
+                        auction.remaining_bid_fills.checked_sub(fill_size).unwrap();
+                }
+                if fill_size > 0 {
+                    // Rounded up, the asks are paid rounded down
+                    let quote_size = bid_quote_qty(fill_size, fill_price)?;
                     let order_fill = Event::Fill {
                         taker_side: side.opposite(),
                         maker_callback_info: order_book
@@ -171,29 +218,32 @@ pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
                     event_queue
                         .push_back(order_fill)
                         .map_err(|_| error!(CustomErrors::AobEventQueueFull))?;
-                    auction.remaining_bid_fills =
-                        auction.remaining_bid_fills.checked_sub(fill_size).unwrap();
-                }
-                let mut out_size = bbo_node.base_quantity - fill_size;
-                // Bids get a partial refund if they're filled at a lower price
-                if fill_size > 0 && bbo_node.price() > auction.clearing_price {
-                    let quote_owed = fp32_mul(fill_size, bbo_node.price()).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?
-                        .checked_sub(fp32_mul(fill_size, auction.clearing_price).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?)
-                        .unwrap();
-                    // Event::out only takes base size as an argument so
-                    // need to convert quote owed to base using bbo's price
-                    let base_owed = fp32_div(quote_owed, bbo_node.price()).ok_or_else(| | error!(CustomErrors::NumericalOverflow))?;
-                    out_size = out_size.checked_add(base_owed).unwrap();
+                    if auction.is_fixed_supply() {
+                        auction.base_sold = auction.base_sold.checked_add(fill_size).unwrap();
+                        auction.quote_proceeds =
+                            auction.quote_proceeds.checked_add(quote_size).unwrap();
+                    }
                 }
+                let out_size = bbo_node.base_quantity - fill_size;
+                // Everything locked for the bid that wasn't paid for its fill is refunded,
+                // this covers the unfilled size and fills at a lower price than the bid's.
+                // The exact quote amount goes in the callback info since Event::Out only
+                // has a base size.
+                let quote_paid = bid_quote_qty(fill_size, fill_price)?;
+                let quote_refund = bid_quote_qty(bbo_node.base_quantity, bbo_node.price())?
+                    .checked_sub(quote_paid)
+                    .unwrap();
+                let mut callback_info = order_book
+                    .get_tree(side)
+                    .get_callback_info(bbo_node.callback_info_pt as usize)
+                    .to_owned();
+                callback_info[32..40].copy_from_slice(&quote_refund.to_le_bytes());
                 let order_out = Event::Out {
                     side,
                     delete: true,
                     order_id: bbo_node.order_id(),
                     base_size: out_size,
-                    callback_info: order_book
-                        .get_tree(side)
-                        .get_callback_info(bbo_node.callback_info_pt as usize)
-                        .to_owned(),
+                    callback_info,
                 };
                 event_queue
                     .push_back(order_out)
@@ -215,3 +265,233 @@ pub fn match_orders(ctx: Context<MatchOrders>, limit: u16) -> Result<()> {
 
     Ok(())
 }
+
+/// Caps a bid's fill so the user never wins more than their max base. Auctions with a
+/// max base per user or an allowlist need the bid's open orders in the remaining accounts.
+/// The cut from the fill is left for the marginal bids that haven't been matched yet.
+fn capped_bid_fill(
+    auction: &Auction,
+    remaining_accounts: &[AccountInfo],
+    callback_info: &[u8],
+    fill_size: u64,
+) -> Result<u64> {
+    let has_user_limits = auction.max_base_per_user > 0 || auction.allowlist_root != [0; 32];
+    if !has_user_limits || fill_size == 0 {
+        return Ok(fill_size);
+    }
+    let user_pubkey = Pubkey::new_from_array(callback_info[..32].try_into().unwrap());
+    let user_account_info = remaining_accounts
+        .iter()
+        .find(|remaining_account| remaining_account.key() == user_pubkey)
+        .ok_or_else(|| error!(CustomErrors::MissingOpenOrdersPubkeyInRemainingAccounts))?;
+    let mut user_open_orders: Account<OpenOrders> = Account::try_from(user_account_info)?;
+    let max_base = match max_base_for_user(auction, &user_open_orders) {
+        Some(max_base) => max_base,
+        None => return Ok(fill_size),
+    };
+    let capped_fill_size = cmp::min(
+        fill_size,
+        max_base.saturating_sub(user_open_orders.base_qty_filled),
+    );
+    user_open_orders.base_qty_filled = user_open_orders
+        .base_qty_filled
+        .checked_add(capped_fill_size)
+        .unwrap();
+    user_open_orders.exit(&crate::ID)?;
+    Ok(capped_fill_size)
+}
+
+/// The most an order can be filled before accounting for the fills remaining
+/// on its side. Orders past the marginal level never fill, not even with fills
+/// cut from capped bids. Under pro-rata allocation each order at the marginal
+/// level gets its share of the fills still remaining, out of the quantity at
+/// that level still to be matched. Shares are rounded up so that the marginal
+/// level always uses up all of its fills, the last orders at that level absorb
+/// the rounding along with anything capped bids before them couldn't take.
+fn allocated_fill(auction: &mut Auction, bbo_node: &LeafNode, side: AobSide) -> Result<u64> {
+    let (marginal_price, remaining_fills, marginal_quantity) = match side {
+        AobSide::Bid => (
+            auction.final_bid_price,
+            auction.remaining_bid_fills,
+            auction.marginal_bid_quantity,
+        ),
+        AobSide::Ask => (
+            auction.final_ask_price,
+            auction.remaining_ask_fills,
+            auction.marginal_ask_quantity,
+        ),
+    };
+    // Asks below the reserve price cleared as if they were placed at the reserve price
+    let (order_price, is_past_marginal_level) = match side {
+        AobSide::Bid => (bbo_node.price(), bbo_node.price() < marginal_price),
+        AobSide::Ask => {
+            let price = cmp::max(bbo_node.price(), auction.reserve_price);
+            (price, price > marginal_price)
+        }
+    };
+    if is_past_marginal_level {
+        return Ok(0);
+    }
+    if auction.fill_allocation_type != FillAllocationTypes::ProRata
+        || order_price != marginal_price
+        || marginal_quantity == 0
+    {
+        return Ok(bbo_node.base_quantity);
+    }
+    let share = pro_rata_share(bbo_node.base_quantity, remaining_fills, marginal_quantity)?;
+    let marginal_quantity = marginal_quantity
+        .checked_sub(bbo_node.base_quantity)
+        .unwrap();
+    match side {
+        AobSide::Bid => auction.marginal_bid_quantity = marginal_quantity,
+        AobSide::Ask => auction.marginal_ask_quantity = marginal_quantity,
+    }
+    Ok(share)
+}
+
+fn pro_rata_share(base_quantity: u64, marginal_fills: u64, marginal_quantity: u64) -> Result<u64> {
+    let share = (base_quantity as u128)
+        .checked_mul(marginal_fills as u128)
+        .and_then(|numerator| numerator.checked_add(marginal_quantity as u128 - 1))
+        .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?
+        / marginal_quantity as u128;
+    Ok(cmp::min(share as u64, base_quantity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARGINAL_PRICE: u64 = 10;
+
+    fn bid(price: u64, base_quantity: u64) -> LeafNode {
+        LeafNode {
+            key: (price as u128) << 64,
+            callback_info_pt: 0,
+            base_quantity,
+        }
+    }
+
+    fn pro_rata_auction(marginal_quantity: u64, marginal_fills: u64) -> Auction {
+        Auction {
+            fill_allocation_type: FillAllocationTypes::ProRata,
+            final_bid_price: MARGINAL_PRICE,
+            remaining_bid_fills: marginal_fills,
+            marginal_bid_quantity: marginal_quantity,
+            ..Auction::default()
+        }
+    }
+
+    // Fills the bids at the marginal level in book order the way match_orders
+    // does, each capped by the fills remaining and then by the user's cap
+    fn allocate_capped(quantities: &[u64], caps: &[u64], marginal_fills: u64) -> Vec<u64> {
+        let mut auction = pro_rata_auction(quantities.iter().sum(), marginal_fills);
+        quantities
+            .iter()
+            .zip(caps.iter())
+            .map(|(quantity, cap)| {
+                let allocated_size = cmp::min(
+                    allocated_fill(&mut auction, &bid(MARGINAL_PRICE, *quantity), AobSide::Bid)
+                        .unwrap(),
+                    auction.remaining_bid_fills,
+                );
+                let fill = cmp::min(allocated_size, *cap);
+                auction.remaining_bid_fills -= fill;
+                fill
+            })
+            .collect()
+    }
+
+    fn allocate(quantities: &[u64], marginal_fills: u64) -> Vec<u64> {
+        allocate_capped(quantities, &vec![u64::MAX; quantities.len()], marginal_fills)
+    }
+
+    #[test]
+    fn shares_are_proportional_when_they_divide_evenly() {
+        assert_eq!(allocate(&[100, 300], 200), vec![50, 150]);
+    }
+
+    #[test]
+    fn shares_round_up_and_the_last_orders_absorb_the_rounding() {
+        assert_eq!(allocate(&[1, 1, 1], 2), vec![1, 1, 0]);
+        assert_eq!(allocate(&[10, 10, 10], 20), vec![7, 7, 6]);
+    }
+
+    #[test]
+    fn marginal_level_always_uses_up_its_fills() {
+        let quantities: [u64; 5] = [7, 13, 29, 1, 50];
+        for marginal_fills in 0..=quantities.iter().sum::<u64>() {
+            let fills = allocate(&quantities, marginal_fills);
+            assert_eq!(fills.iter().sum::<u64>(), marginal_fills);
+            for (fill, quantity) in fills.iter().zip(quantities.iter()) {
+                assert!(fill <= quantity);
+            }
+        }
+    }
+
+    #[test]
+    fn capped_bids_leave_their_excess_to_the_bids_after_them() {
+        assert_eq!(
+            allocate_capped(&[10, 10, 10], &[1, u64::MAX, u64::MAX], 15),
+            vec![1, 7, 7]
+        );
+        // The last bid can't take more than its own size
+        assert_eq!(
+            allocate_capped(&[10, 10, 10], &[0, u64::MAX, u64::MAX], 25),
+            vec![0, 10, 10]
+        );
+    }
+
+    #[test]
+    fn bids_past_the_marginal_level_never_fill() {
+        let mut auction = pro_rata_auction(10, 5);
+        assert_eq!(
+            allocated_fill(&mut auction, &bid(MARGINAL_PRICE - 1, 10), AobSide::Bid).unwrap(),
+            0
+        );
+        auction.fill_allocation_type = FillAllocationTypes::PriceTime;
+        assert_eq!(
+            allocated_fill(&mut auction, &bid(MARGINAL_PRICE - 1, 10), AobSide::Bid).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn quote_vault_covers_the_asks_with_many_fractional_fills() {
+        let bid_quantities: Vec<u64> = (0..40).map(|i| i % 7 + 1).collect();
+        let ask_quantities: Vec<u64> = (0..25).map(|i| i % 11 + 1).collect();
+        let max_fills = cmp::min(
+            bid_quantities.iter().sum::<u64>(),
+            ask_quantities.iter().sum::<u64>(),
+        );
+        // Clearing prices between 0.5 and 3 quote lots, none of them whole
+        for clearing_price in ((1u64 << 31) + 1..3 << 32).step_by(977_123_457) {
+            for fills in 1..=max_fills {
+                let mut quote_in: u64 = 0;
+                for (quantity, fill) in bid_quantities
+                    .iter()
+                    .zip(allocate(&bid_quantities, fills).iter())
+                {
+                    let locked = bid_quote_qty(*quantity, clearing_price).unwrap();
+                    let paid = bid_quote_qty(*fill, clearing_price).unwrap();
+                    assert!(paid <= locked, "the refund can't be negative");
+                    quote_in += paid;
+                }
+                let quote_out: u64 = allocate(&ask_quantities, fills)
+                    .iter()
+                    .map(|fill| fp32_mul(*fill, clearing_price).unwrap())
+                    .sum();
+                assert!(quote_out <= quote_in);
+            }
+        }
+    }
+
+    #[test]
+    fn share_never_exceeds_the_order() {
+        assert_eq!(pro_rata_share(5, 10, 10).unwrap(), 5);
+        assert_eq!(
+            pro_rata_share(u64::MAX, u64::MAX, u64::MAX).unwrap(),
+            u64::MAX
+        );
+    }
+}
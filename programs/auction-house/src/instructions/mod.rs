@@ -1,25 +1,59 @@
+pub use abort_auction::*;
+pub use amend_order::*;
 pub use calculate_clearing_price::*;
+pub use cancel_all_orders::*;
 pub use cancel_encrypted_order::*;
 pub use cancel_order::*;
+pub use claim_vested::*;
 pub use close_aob_accounts::*;
+pub use close_auction::*;
+pub use close_order_history::*;
+pub use close_vesting::*;
 pub use consume_events::*;
 pub use decrypt_order::*;
+pub use deposit_fixed_supply::*;
 pub use init_auction::*;
 pub use init_open_orders::*;
 pub use match_orders::*;
 pub use new_encrypted_order::*;
 pub use new_order::*;
+pub use new_orders::*;
+pub use reveal_order::*;
+pub use reveal_own_orders::*;
+pub use reveal_reserve_price::*;
 pub use settle_and_close_open_orders::*;
+pub use settle_open_orders_batch::*;
+pub use submit_key_share::*;
+pub use sweep_dust::*;
+pub use update_auction::*;
+pub use withdraw_proceeds::*;
 
+mod abort_auction;
+mod amend_order;
 mod calculate_clearing_price;
+mod cancel_all_orders;
 mod cancel_encrypted_order;
 mod cancel_order;
+mod claim_vested;
 mod close_aob_accounts;
+mod close_auction;
+mod close_order_history;
+mod close_vesting;
 mod consume_events;
 mod decrypt_order;
+mod deposit_fixed_supply;
 mod init_auction;
 mod init_open_orders;
 mod match_orders;
 mod new_encrypted_order;
 mod new_order;
+mod new_orders;
+mod reveal_order;
+mod reveal_own_orders;
+mod reveal_reserve_price;
 mod settle_and_close_open_orders;
+mod settle_open_orders_batch;
+mod submit_key_share;
+mod sweep_dust;
+mod update_auction;
+mod withdraw_proceeds;
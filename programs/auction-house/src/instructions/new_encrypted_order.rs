@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
-use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
+use crate::token_interface::{
+    amount_with_transfer_fee, get_associated_token_address, transfer_checked, TransferChecked,
+};
 use crate::types::*;
 
 #[derive(Accounts)]
@@ -25,40 +27,53 @@ pub struct NewEncryptedOrder<'info> {
     )]
     pub open_orders: Box<Account<'info, OpenOrders>>,
     // Token accounts
+    /// CHECK: The auction's quote mint
     #[account(address = auction.quote_mint)]
-    pub quote_mint: Box<Account<'info, Mint>>,
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
     #[account(address = auction.base_mint)]
-    pub base_mint: Box<Account<'info, Mint>>,
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = quote_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.quote_mint, &auction.quote_token_program),
         mut
     )]
-    pub user_quote: Account<'info, TokenAccount>,
+    pub user_quote: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = base_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.base_mint, &auction.base_token_program),
         mut
     )]
-    pub user_base: Account<'info, TokenAccount>,
+    pub user_base: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.quote_vault,
         mut
     )]
-    pub quote_vault: Account<'info, TokenAccount>,
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.base_vault,
         mut
     )]
-    pub base_vault: Account<'info, TokenAccount>,
+    pub base_vault: UncheckedAccount<'info>,
     // Programs
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
 }
 
 impl NewEncryptedOrder<'_> {
-    pub fn access_control_new_encrypted_order(&self, nacl_pubkey: &Vec<u8>) -> Result<()> {
+    pub fn access_control_new_encrypted_order(
+        &self,
+        nacl_pubkey: &Vec<u8>,
+        cipher_text: &[u8],
+    ) -> Result<()> {
         let clock = Clock::get()?;
         let auction = self.auction.clone().into_inner();
         let open_orders = self.open_orders.clone().into_inner();
@@ -73,6 +88,10 @@ impl NewEncryptedOrder<'_> {
         {
             return Err(error!(CustomErrors::EncryptionPubkeysDoNotMatch));
         }
+        // Commit reveal orders are a keccak hash of the plaintext order and a salt
+        if auction.is_commit_reveal && cipher_text.len() != 32 {
+            return Err(error!(CustomErrors::InvalidCommitment));
+        }
         Ok(())
     }
 
@@ -84,14 +103,16 @@ impl NewEncryptedOrder<'_> {
         // Encrypted orders can be cancelled only during the order phase or after
         // the decryption phase is over.
         // Needed in case there are still encrypted orders leftover after the decryption phase ends.
-        if clock.unix_timestamp < auction.end_decryption_phase
+        if !auction.is_cancelled
+            && clock.unix_timestamp < auction.end_decryption_phase
             && !is_order_phase_active(clock, &auction)
         {
             return Err(error!(CustomErrors::OrderPhaseNotActive));
         }
         encrypted_orders_only(&auction, &open_orders)?;
 
-        if self.open_orders.num_orders <= order_idx {
+        // Revealed orders still count towards num_orders but are no longer encrypted
+        if open_orders.encrypted_orders.len() <= order_idx as usize {
             return Err(error!(CustomErrors::OrderIdxNotValid));
         }
 
@@ -100,37 +121,41 @@ impl NewEncryptedOrder<'_> {
 }
 
 impl<'info> NewEncryptedOrder<'info> {
-    pub fn transfer_user_base(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_user_base(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.user_base.to_account_info(),
+            mint: self.base_mint.to_account_info(),
             to: self.base_vault.to_account_info(),
             authority: self.user.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_user_quote(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_user_quote(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.user_quote.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
             to: self.quote_vault.to_account_info(),
             authority: self.user.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
             to: self.user_base.to_account_info(),
             authority: self.auction.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.quote_vault.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
             to: self.user_quote.to_account_info(),
             authority: self.auction.to_account_info(),
         };
@@ -164,6 +189,13 @@ pub fn new_encrypted_order(
     };
     open_orders.encrypted_orders.push(this_order);
     open_orders.num_orders += 1;
+    emit!(EncryptedOrderPlaced {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        token_qty,
+    });
 
     match open_orders.side {
         Side::Ask => {
@@ -171,14 +203,26 @@ pub fn new_encrypted_order(
                 .base_token_locked
                 .checked_add(token_qty)
                 .unwrap();
-            token::transfer(ctx.accounts.transfer_user_base(), token_qty)?;
+            transfer_checked(
+                ctx.accounts.transfer_user_base(),
+                amount_with_transfer_fee(
+                    &ctx.accounts.base_mint,
+                    ctx.accounts.auction.base_lots_to_atoms(token_qty),
+                )?,
+            )?;
         }
         Side::Bid => {
             open_orders.quote_token_locked = open_orders
                 .quote_token_locked
                 .checked_add(token_qty)
                 .unwrap();
-            token::transfer(ctx.accounts.transfer_user_quote(), token_qty)?;
+            transfer_checked(
+                ctx.accounts.transfer_user_quote(),
+                amount_with_transfer_fee(
+                    &ctx.accounts.quote_mint,
+                    ctx.accounts.auction.quote_lots_to_atoms(token_qty),
+                )?,
+            )?;
         }
     }
 
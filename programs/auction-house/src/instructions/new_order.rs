@@ -1,11 +1,15 @@
-use anchor_lang::prelude::*;
+use std::cmp;
 
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_lang::prelude::*;
 
 use crate::access_controls::*;
 use crate::consts::*;
 use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
+use crate::token_interface::{
+    amount_with_transfer_fee, get_associated_token_address, transfer_checked, TransferChecked,
+};
 use crate::types::*;
 
 use agnostic_orderbook::orderbook::OrderBookState;
@@ -18,6 +22,7 @@ pub struct NewOrder<'info> {
     #[account(
         seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bump,
+        mut
     )]
     pub auction: Box<Account<'info, Auction>>,
     #[account(
@@ -49,36 +54,45 @@ pub struct NewOrder<'info> {
     )]
     pub asks: UncheckedAccount<'info>,
     // Token accounts
+    /// CHECK: The auction's quote mint
     #[account(address = auction.quote_mint)]
-    pub quote_mint: Box<Account<'info, Mint>>,
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
     #[account(address = auction.base_mint)]
-    pub base_mint: Box<Account<'info, Mint>>,
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = quote_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.quote_mint, &auction.quote_token_program),
         mut
     )]
-    pub user_quote: Account<'info, TokenAccount>,
+    pub user_quote: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = base_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.base_mint, &auction.base_token_program),
         mut
     )]
-    pub user_base: Account<'info, TokenAccount>,
+    pub user_base: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.quote_vault,
         mut
     )]
-    pub quote_vault: Account<'info, TokenAccount>,
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.base_vault,
         mut
     )]
-    pub base_vault: Account<'info, TokenAccount>,
+    pub base_vault: UncheckedAccount<'info>,
     // Programs
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
 }
 
 impl NewOrder<'_> {
@@ -93,20 +107,37 @@ impl NewOrder<'_> {
         normal_orders_only(&auction, &open_orders)?;
         has_space_for_new_orders(&open_orders)?;
         validate_price_and_qty(&auction, limit_price, max_base_qty)?;
+        has_space_under_max_base_per_user(&auction, &open_orders, max_base_qty)?;
 
         Ok(())
     }
 
+    pub fn access_control_new_quote_budget_order(
+        &self,
+        limit_price: u64,
+        max_quote_qty: u64,
+    ) -> Result<()> {
+        if self.open_orders.side != Side::Bid {
+            return Err(error!(CustomErrors::QuoteBudgetOnlyForBids));
+        }
+        let max_base_qty = base_qty_from_quote_budget(limit_price, max_quote_qty)?;
+        self.access_control_new_order(limit_price, max_base_qty)
+    }
+
     // TODO move this to cancel_order.rs
     pub fn access_control_cancel_order(&self, order_id: &u128) -> Result<()> {
         let clock = Clock::get()?;
         let auction = self.auction.clone().into_inner();
         let open_orders = self.open_orders.clone().into_inner();
 
-        if !is_order_phase_active(clock, &auction) {
-            return Err(error!(CustomErrors::OrderPhaseNotActive));
+        // Once the auction is cancelled orders can be pulled at any time,
+        // including orders that were decrypted onto the book
+        if !auction.is_cancelled {
+            if !is_order_phase_active(clock, &auction) {
+                return Err(error!(CustomErrors::OrderPhaseNotActive));
+            }
+            normal_orders_only(&auction, &open_orders)?;
         }
-        normal_orders_only(&auction, &open_orders)?;
 
         // Validate the order id is present, will error inside function if not
         let _ = self.open_orders.find_order_index(order_id)?;
@@ -116,37 +147,41 @@ impl NewOrder<'_> {
 }
 
 impl<'info> NewOrder<'info> {
-    pub fn transfer_user_base(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_user_base(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.user_base.to_account_info(),
+            mint: self.base_mint.to_account_info(),
             to: self.base_vault.to_account_info(),
             authority: self.user.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_user_quote(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_user_quote(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.user_quote.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
             to: self.quote_vault.to_account_info(),
             authority: self.user.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
             to: self.user_base.to_account_info(),
             authority: self.auction.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.quote_vault.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
             to: self.user_quote.to_account_info(),
             authority: self.auction.to_account_info(),
         };
@@ -155,6 +190,27 @@ impl<'info> NewOrder<'info> {
 }
 
 pub fn new_order(ctx: Context<NewOrder>, limit_price: u64, max_base_qty: u64) -> Result<()> {
+    place_order(ctx, limit_price, max_base_qty, u64::MAX)
+}
+
+/// Bids for as much base as the quote budget covers at the limit price. Only
+/// the quote for that much base is locked, which is at most max_quote_qty, any
+/// of the budget that doesn't buy a whole lot stays with the user.
+pub fn new_quote_budget_order(
+    ctx: Context<NewOrder>,
+    limit_price: u64,
+    max_quote_qty: u64,
+) -> Result<()> {
+    let max_base_qty = base_qty_from_quote_budget(limit_price, max_quote_qty)?;
+    place_order(ctx, limit_price, max_base_qty, max_quote_qty)
+}
+
+fn place_order(
+    ctx: Context<NewOrder>,
+    limit_price: u64,
+    max_base_qty: u64,
+    max_quote_qty: u64,
+) -> Result<()> {
     let mut order_book = OrderBookState::new_safe(
         &ctx.accounts.bids.to_account_info(),
         &ctx.accounts.asks.to_account_info(),
@@ -177,8 +233,7 @@ pub fn new_order(ctx: Context<NewOrder>, limit_price: u64, max_base_qty: u64) ->
     let params =
         ctx.accounts
             .open_orders
-            .new_order_params(limit_price, max_base_qty);
-    msg!("params: {:?}", params);
+            .new_order_params(limit_price, max_base_qty, max_quote_qty);
     let order_summary = order_book
         .new_order(
             params,
@@ -191,31 +246,58 @@ pub fn new_order(ctx: Context<NewOrder>, limit_price: u64, max_base_qty: u64) ->
     open_orders
         .orders
         .push(order_summary.posted_order_id.unwrap());
+    open_orders.order_records.push(OrderRecord::new(
+        order_summary.posted_order_id.unwrap(),
+        limit_price,
+        order_summary.total_base_qty,
+    ));
     open_orders.num_orders += 1;
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_add(order_summary.total_base_qty)
+        .unwrap();
+
+    // Never more than the budget for quote budget orders, the base was rounded down
+    let total_quote_qty = bid_quote_qty(order_summary.total_base_qty, limit_price)?;
+    // Emitted before the transfers, they need ctx.accounts back from open_orders
+    emit!(OrderPlaced {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        side: open_orders.side,
+        order_id: order_summary.posted_order_id.unwrap(),
+        limit_price,
+        base_qty: order_summary.total_base_qty,
+        quote_qty: total_quote_qty,
+    });
 
     match open_orders.side {
         Side::Ask => {
-            msg!("max base qty: {}, limit price in FP32: {}", max_base_qty, limit_price);
-            msg!("order summary {:?}", order_summary);
             open_orders.base_token_locked = open_orders
                 .base_token_locked
                 .checked_add(order_summary.total_base_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts.transfer_user_base(),
-                order_summary.total_base_qty,
+                amount_with_transfer_fee(
+                    &ctx.accounts.base_mint,
+                    ctx.accounts
+                        .auction
+                        .base_lots_to_atoms(order_summary.total_base_qty),
+                )?,
             )?;
         }
         Side::Bid => {
-            msg!("max base qty: {}, limit price in FP32: {}", max_base_qty, limit_price);
-            msg!("order summary {:?}", order_summary);
             open_orders.quote_token_locked = open_orders
                 .quote_token_locked
-                .checked_add(order_summary.total_quote_qty)
+                .checked_add(total_quote_qty)
                 .unwrap();
-            token::transfer(
+            transfer_checked(
                 ctx.accounts.transfer_user_quote(),
-                order_summary.total_quote_qty,
+                amount_with_transfer_fee(
+                    &ctx.accounts.quote_mint,
+                    ctx.accounts.auction.quote_lots_to_atoms(total_quote_qty),
+                )?,
             )?;
         }
     }
@@ -227,5 +309,32 @@ pub fn new_order(ctx: Context<NewOrder>, limit_price: u64, max_base_qty: u64) ->
         .serialize(&mut event_queue_header_data)
         .unwrap();
 
+    extend_order_phase(&mut ctx.accounts.auction, Clock::get()?)?;
+
+    Ok(())
+}
+
+/// Orders placed within the soft close window push the end of the order and
+/// decryption phases back, up to the auction's max total extension.
+pub fn extend_order_phase(auction: &mut Auction, clock: Clock) -> Result<()> {
+    if auction.soft_close_window == 0
+        || clock.unix_timestamp < auction.end_order_phase - auction.soft_close_window
+    {
+        return Ok(());
+    }
+    let extension = cmp::min(
+        auction.soft_close_extension,
+        auction.max_soft_close_extension - auction.total_soft_close_extension,
+    );
+    if extension <= 0 {
+        return Ok(());
+    }
+    auction.end_order_phase = auction.end_order_phase.checked_add(extension).unwrap();
+    auction.end_decryption_phase = auction.end_decryption_phase.checked_add(extension).unwrap();
+    auction.total_soft_close_extension = auction
+        .total_soft_close_extension
+        .checked_add(extension)
+        .unwrap();
+    msg!("order phase extended to {}", auction.end_order_phase);
     Ok(())
 }
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::{EventQueue, EventQueueHeader, EVENT_QUEUE_HEADER_LEN};
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::instructions::{extend_order_phase, NewOrder};
+use crate::token_interface::{amount_with_transfer_fee, transfer_checked};
+use crate::types::*;
+
+impl NewOrder<'_> {
+    pub fn access_control_new_orders(&self, orders: &[OrderArgs]) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+        let open_orders = self.open_orders.clone().into_inner();
+
+        if !is_order_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::OrderPhaseNotActive));
+        }
+        normal_orders_only(&auction, &open_orders)?;
+        if orders.is_empty() {
+            return Err(error!(CustomErrors::NoOrdersToPlace));
+        }
+        if open_orders.max_orders < open_orders.num_orders
+            || ((open_orders.max_orders - open_orders.num_orders) as usize) < orders.len()
+        {
+            return Err(error!(CustomErrors::TooManyOrders));
+        }
+        let mut total_base_qty: u64 = 0;
+        for order in orders.iter() {
+            validate_price_and_qty(&auction, order.limit_price, order.max_base_qty)?;
+            total_base_qty = total_base_qty
+                .checked_add(order.max_base_qty)
+                .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?;
+        }
+        has_space_under_max_base_per_user(&auction, &open_orders, total_base_qty)?;
+
+        Ok(())
+    }
+}
+
+/// Places several orders at once, e.g. a price ladder, with a single token
+/// transfer for the total amount locked
+pub fn new_orders(ctx: Context<NewOrder>, orders: Vec<OrderArgs>) -> Result<()> {
+    let mut order_book = OrderBookState::new_safe(
+        &ctx.accounts.bids.to_account_info(),
+        &ctx.accounts.asks.to_account_info(),
+        CALLBACK_INFO_LEN,
+        CALLBACK_ID_LEN,
+    )?;
+    let header = {
+        let mut event_queue_data: &[u8] =
+            &ctx.accounts.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+        EventQueueHeader::deserialize(&mut event_queue_data)
+            .unwrap()
+            .check()?
+    };
+    let mut event_queue = EventQueue::new_safe(
+        header,
+        &ctx.accounts.event_queue.to_account_info(),
+        CALLBACK_INFO_LEN,
+    )?;
+
+    let open_orders = &mut *ctx.accounts.open_orders;
+    let mut total_base_qty: u64 = 0;
+    let mut total_quote_qty: u64 = 0;
+    for order in orders.iter() {
+        let params = open_orders.new_order_params(order.limit_price, order.max_base_qty, u64::MAX);
+        let order_summary = order_book
+            .new_order(
+                params,
+                &mut event_queue,
+                ctx.accounts.auction.min_base_order_size,
+            )
+            .unwrap();
+        open_orders
+            .orders
+            .push(order_summary.posted_order_id.unwrap());
+        open_orders.order_records.push(OrderRecord::new(
+            order_summary.posted_order_id.unwrap(),
+            order.limit_price,
+            order_summary.total_base_qty,
+        ));
+        open_orders.num_orders += 1;
+        let quote_qty = bid_quote_qty(order_summary.total_base_qty, order.limit_price)?;
+        total_base_qty = total_base_qty
+            .checked_add(order_summary.total_base_qty)
+            .unwrap();
+        total_quote_qty = total_quote_qty.checked_add(quote_qty).unwrap();
+        emit!(OrderPlaced {
+            auction: open_orders.auction,
+            open_orders: open_orders.this_open_orders,
+            user: open_orders.authority,
+            side: open_orders.side,
+            order_id: order_summary.posted_order_id.unwrap(),
+            limit_price: order.limit_price,
+            base_qty: order_summary.total_base_qty,
+            quote_qty,
+        });
+    }
+    open_orders.base_qty_ordered = open_orders
+        .base_qty_ordered
+        .checked_add(total_base_qty)
+        .unwrap();
+
+    match open_orders.side {
+        Side::Ask => {
+            open_orders.base_token_locked = open_orders
+                .base_token_locked
+                .checked_add(total_base_qty)
+                .unwrap();
+            transfer_checked(
+                ctx.accounts.transfer_user_base(),
+                amount_with_transfer_fee(
+                    &ctx.accounts.base_mint,
+                    ctx.accounts.auction.base_lots_to_atoms(total_base_qty),
+                )?,
+            )?;
+        }
+        Side::Bid => {
+            open_orders.quote_token_locked = open_orders
+                .quote_token_locked
+                .checked_add(total_quote_qty)
+                .unwrap();
+            transfer_checked(
+                ctx.accounts.transfer_user_quote(),
+                amount_with_transfer_fee(
+                    &ctx.accounts.quote_mint,
+                    ctx.accounts.auction.quote_lots_to_atoms(total_quote_qty),
+                )?,
+            )?;
+        }
+    }
+
+    order_book.commit_changes();
+    let mut event_queue_header_data: &mut [u8] = &mut ctx.accounts.event_queue.data.borrow_mut();
+    event_queue
+        .header
+        .serialize(&mut event_queue_header_data)
+        .unwrap();
+
+    // Only a batch that posted orders can push the soft close back
+    if !orders.is_empty() {
+        extend_order_phase(&mut ctx.accounts.auction, Clock::get()?)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::{EventQueue, EventQueueHeader, EVENT_QUEUE_HEADER_LEN};
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::instructions::{post_revealed_order, RevealOwnOrders};
+
+impl RevealOwnOrders<'_> {
+    pub fn access_control_reveal_order(
+        &self,
+        order_idx: u8,
+        price_and_quantity: &[u8],
+        salt: &[u8; 32],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+        let open_orders = self.open_orders.clone().into_inner();
+
+        if !is_decryption_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::DecryptionPhaseNotActive));
+        };
+        encrypted_orders_only(&auction, &open_orders)?;
+        if !auction.is_commit_reveal {
+            return Err(error!(CustomErrors::CommitRevealAuctionsOnly));
+        }
+
+        let commitment = &open_orders
+            .encrypted_orders
+            .get(order_idx as usize)
+            .ok_or_else(|| error!(CustomErrors::OrderIdxNotValid))?
+            .cipher_text;
+        if !(16..=17).contains(&price_and_quantity.len())
+            || keccak::hashv(&[price_and_quantity, salt]).0[..] != commitment[..]
+        {
+            return Err(error!(CustomErrors::InvalidCommitment));
+        }
+
+        Ok(())
+    }
+}
+
+/// Posts a single commit reveal order once the user shows the plaintext
+/// order and salt that hash to its commitment
+pub fn reveal_order(
+    ctx: Context<RevealOwnOrders>,
+    order_idx: u8,
+    price_and_quantity: Vec<u8>,
+) -> Result<()> {
+    let mut order_book = OrderBookState::new_safe(
+        &ctx.accounts.bids.to_account_info(),
+        &ctx.accounts.asks.to_account_info(),
+        CALLBACK_INFO_LEN,
+        CALLBACK_ID_LEN,
+    )?;
+    let header = {
+        let mut event_queue_data: &[u8] =
+            &ctx.accounts.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+        EventQueueHeader::deserialize(&mut event_queue_data)
+            .unwrap()
+            .check()?
+    };
+    let mut event_queue = EventQueue::new_safe(
+        header,
+        &ctx.accounts.event_queue.to_account_info(),
+        CALLBACK_INFO_LEN,
+    )?;
+
+    let open_orders = &mut *ctx.accounts.open_orders;
+    let this_order = open_orders.encrypted_orders.remove(order_idx as usize);
+    post_revealed_order(
+        &ctx.accounts.auction,
+        open_orders,
+        &mut order_book,
+        &mut event_queue,
+        this_order.token_qty,
+        &price_and_quantity,
+    )?;
+
+    order_book.commit_changes();
+    let mut event_queue_header_data: &mut [u8] = &mut ctx.accounts.event_queue.data.borrow_mut();
+    event_queue
+        .header
+        .serialize(&mut event_queue_header_data)
+        .unwrap();
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::encryption::{clamp_secret_key, nacl_shared_key, public_key};
+use crate::error::CustomErrors;
+use crate::instructions::decrypt_and_post_orders;
+use crate::program_accounts::*;
+
+#[derive(Accounts)]
+pub struct RevealOwnOrders<'info> {
+    pub user: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    #[account(
+        seeds = [user.key().as_ref(), OPEN_ORDERS.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = open_orders.bump,
+        mut
+    )]
+    pub open_orders: Box<Account<'info, OpenOrders>>,
+    // AOB Accounts
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.event_queue,
+        owner = crate::ID,
+        mut
+    )]
+    pub event_queue: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.bids,
+        owner = crate::ID,
+        mut
+    )]
+    pub bids: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.asks,
+        owner = crate::ID,
+        mut
+    )]
+    pub asks: UncheckedAccount<'info>,
+}
+
+impl RevealOwnOrders<'_> {
+    pub fn access_control(&self, secret_key: &[u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+        let open_orders = self.open_orders.clone().into_inner();
+
+        if !is_decryption_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::DecryptionPhaseNotActive));
+        };
+        encrypted_orders_only(&auction, &open_orders)?;
+        if auction.is_commit_reveal {
+            return Err(error!(CustomErrors::NotAvailableForCommitReveal));
+        }
+        // Only the key the orders were encrypted with, see crate::encryption
+        if public_key(&clamp_secret_key(*secret_key))[..] != open_orders.nacl_pubkey[..] {
+            return Err(error!(CustomErrors::EncryptionPubkeysDoNotMatch));
+        }
+
+        Ok(())
+    }
+}
+
+/// Lets the user post their own encrypted orders so the auctioneer can't
+/// censor them by never decrypting. decrypt_order works from the auction's side
+/// of the key exchange, this works from the user's: they hand over the secret
+/// key of their open orders' nacl_pubkey, which should only ever be used for
+/// this auction, and the shared key is derived from it and the auction's key.
+pub fn reveal_own_orders(ctx: Context<RevealOwnOrders>, secret_key: [u8; 32]) -> Result<()> {
+    let shared_key = nacl_shared_key(
+        &clamp_secret_key(secret_key),
+        &ctx.accounts.auction.nacl_pubkey,
+    )?;
+    decrypt_and_post_orders(
+        &ctx.accounts.auction,
+        &mut ctx.accounts.open_orders,
+        &ctx.accounts.event_queue,
+        &ctx.accounts.bids,
+        &ctx.accounts.asks,
+        &shared_key,
+    )
+}
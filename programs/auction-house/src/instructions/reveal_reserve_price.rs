@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+
+#[derive(Accounts)]
+pub struct RevealReservePrice<'info> {
+    pub auctioneer: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+}
+
+impl RevealReservePrice<'_> {
+    pub fn access_control(&self, reserve_price: u64, salt: &[u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+
+        if !is_decryption_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::DecryptionPhaseNotActive));
+        }
+        if auction.reserve_price_commitment == [0; 32] {
+            return Err(error!(CustomErrors::NoEncryptedReservePrice));
+        }
+        // The commitment binds the auctioneer to a single price before any bids are seen
+        if keccak::hashv(&[&reserve_price.to_le_bytes(), salt]).0
+            != auction.reserve_price_commitment
+        {
+            return Err(error!(CustomErrors::InvalidReservePriceKey));
+        }
+        if reserve_price % auction.tick_size != 0 {
+            return Err(error!(CustomErrors::ReservePriceNotAMultipleOfTickSize));
+        }
+
+        Ok(())
+    }
+}
+
+pub fn reveal_reserve_price(ctx: Context<RevealReservePrice>, reserve_price: u64) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    auction.reserve_price = reserve_price;
+    auction.reserve_price_commitment = [0; 32];
+    msg!("reserve price revealed: {}", reserve_price);
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token;
-use anchor_spl::token::{Mint, Token, TokenAccount};
 
+use crate::access_controls::*;
 use crate::consts::*;
-use crate::error::CustomErrors;
+use crate::events::*;
 use crate::program_accounts::*;
+use crate::token_interface::{get_associated_token_address, transfer_checked, TransferChecked};
+use crate::utils::realloc_program_account;
 
 // Flexible on design decisions such as:
 // whether this function should be signed by the auctioneer
@@ -18,6 +19,7 @@ pub struct SettleAndCloseOpenOrders<'info> {
     #[account(
         seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bump,
+        mut
     )]
     pub auction: Box<Account<'info, Auction>>,
     #[account(
@@ -33,69 +35,79 @@ pub struct SettleAndCloseOpenOrders<'info> {
         mut
     )]
     pub order_history: Account<'info, OrderHistory>, // Persists after open_orders has closed
+    /// CHECK: Only exists, and is only read, if the user's purchased base vests
+    #[account(
+        seeds = [user.key().as_ref(), VESTING.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump,
+        mut
+    )]
+    pub vesting: UncheckedAccount<'info>,
     // Token Accounts
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.quote_vault,
         mut
     )]
-    pub quote_vault: Account<'info, TokenAccount>,
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
     #[account(
         seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
         bump = auction.bumps.base_vault,
         mut
     )]
-    pub base_vault: Account<'info, TokenAccount>,
+    pub base_vault: UncheckedAccount<'info>,
+    /// CHECK: The auction's quote mint
     #[account(address = auction.quote_mint)]
-    pub quote_mint: Box<Account<'info, Mint>>,
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
     #[account(address = auction.base_mint)]
-    pub base_mint: Box<Account<'info, Mint>>,
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = quote_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.quote_mint, &auction.quote_token_program),
         mut
     )]
-    pub user_quote: Account<'info, TokenAccount>,
+    pub user_quote: UncheckedAccount<'info>,
+    /// CHECK: The user's associated token account, the token program checks the rest
     #[account(
-        associated_token::mint = base_mint,
-        associated_token::authority = user,
+        address = get_associated_token_address(&user.key(), &auction.base_mint, &auction.base_token_program),
         mut
     )]
-    pub user_base: Account<'info, TokenAccount>,
+    pub user_base: UncheckedAccount<'info>,
     // Programs
     pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
 }
 
 impl SettleAndCloseOpenOrders<'_> {
     // There are no specific time restrictions on when settle and close can be called
     pub fn access_control(&self) -> Result<()> {
-        if self.open_orders.num_orders > 0 {
-            return Err(error!(CustomErrors::OpenOrdersHasOpenOrders));
-        }
-        // Turned out this is not a redundant check, AOB has rounding errors which leave small amounts in the account.
-        // So now we have dust thresholds, in case there are small amounts left over in the account.
-        if self.open_orders.quote_token_locked > QUOTE_DUST_THRESHOLD || self.open_orders.base_token_locked > BASE_DUST_THRESHOLD {
-            return Err(error!(CustomErrors::OpenOrdersHasLockedTokens));
-        }
-        Ok(())
+        can_settle_open_orders(&self.open_orders)
     }
 }
 
 impl<'info> SettleAndCloseOpenOrders<'info> {
-    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
             to: self.user_base.to_account_info(),
             authority: self.auction.to_account_info(),
         };
         CpiContext::new(program, accounts)
     }
-    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let program = self.token_program.to_account_info();
-        let accounts = token::Transfer {
+    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
             from: self.quote_vault.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
             to: self.user_quote.to_account_info(),
             authority: self.auction.to_account_info(),
         };
@@ -104,41 +116,195 @@ impl<'info> SettleAndCloseOpenOrders<'info> {
 }
 
 pub fn settle_and_close_open_orders(ctx: Context<SettleAndCloseOpenOrders>) -> Result<()> {
-    let open_orders = &mut *ctx.accounts.open_orders;
-
-    // This information + the clearing price should be enough to display to the user after the auction
-    ctx.accounts.order_history.set_inner(OrderHistory {
-        bump: ctx.accounts.order_history.bump,
-        auction: open_orders.auction,
-        side: open_orders.side,
-        quote_amount_returned: open_orders.quote_token_free,
-        base_amount_returned: open_orders.base_token_free,
-    });
-
-    // We have to set open orders.free values to 0 before calling the CPI
-    // because of an immutable borrow compile error. Technically it would be
-    // safe to omit setting the free values to 0 because of the anchor
-    // account close discriminator but better to be totally sure.
-    let quote_token_free = open_orders.quote_token_free;
-    let base_token_free = open_orders.base_token_free;
-    open_orders.quote_token_free = 0;
-    open_orders.base_token_free = 0;
+    let vesting_info = ctx.accounts.vesting.to_account_info();
+    let mut vesting: Option<Account<Vesting>> = match ctx
+        .accounts
+        .auction
+        .needs_vesting_account(ctx.accounts.open_orders.side)
+    {
+        true => Some(Account::try_from(&vesting_info)?),
+        false => None,
+    };
+    let (quote_token_free, base_token_free) = settle_open_orders(
+        &mut ctx.accounts.auction,
+        &mut ctx.accounts.open_orders,
+        &mut ctx.accounts.order_history,
+        vesting.as_deref_mut(),
+    );
+    // The open orders account is about to be closed, its rent pays for the records
+    realloc_program_account(
+        &ctx.accounts.order_history.to_account_info(),
+        &ctx.accounts.open_orders.to_account_info(),
+        ctx.accounts.order_history.space(),
+    )?;
+    if let Some(vesting) = vesting {
+        vesting.exit(ctx.program_id)?;
+    }
     if quote_token_free > 0 {
-        token::transfer(
+        transfer_checked(
             ctx.accounts
                 .transfer_quote_vault()
                 .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-            quote_token_free,
+            ctx.accounts.auction.quote_lots_to_atoms(quote_token_free),
         )?;
     }
     if base_token_free > 0 {
-        token::transfer(
+        transfer_checked(
             ctx.accounts
                 .transfer_base_vault()
                 .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
-            base_token_free,
+            ctx.accounts.auction.base_lots_to_atoms(base_token_free),
         )?;
     }
 
     Ok(())
 }
+
+/// Records the order history and empties the open orders account's free
+/// balances, returning the quote and base to send to the user. Purchased base
+/// stays in the vault and is released through claim_vested if the auction vests,
+/// in which case the bidder's vesting account has to be passed in.
+pub fn settle_open_orders(
+    auction: &mut Auction,
+    open_orders: &mut OpenOrders,
+    order_history: &mut OrderHistory,
+    vesting: Option<&mut Vesting>,
+) -> (u64, u64) {
+    // Everything needed to display the user's result after the auction, down to each order's fills
+    *order_history = OrderHistory {
+        bump: order_history.bump,
+        auction: open_orders.auction,
+        side: open_orders.side,
+        quote_amount_returned: open_orders.quote_token_free,
+        base_amount_returned: open_orders.base_token_free,
+        clearing_price: auction.clearing_price,
+        orders: open_orders.order_records.clone(),
+    };
+
+    auction.num_open_orders = auction.num_open_orders.checked_sub(1).unwrap();
+
+    // Technically it would be safe to omit setting the free values to 0
+    // because of the anchor account close discriminator but better to be
+    // totally sure.
+    let quote_token_free = open_orders.quote_token_free;
+    let base_token_free = open_orders.base_token_free;
+    open_orders.quote_token_free = 0;
+    open_orders.base_token_free = 0;
+    let base_token_vesting = match vesting {
+        Some(vesting) => {
+            vesting.base_amount_vesting = vesting
+                .base_amount_vesting
+                .checked_add(base_token_free)
+                .unwrap();
+            auction.base_vesting_unclaimed = auction
+                .base_vesting_unclaimed
+                .checked_add(base_token_free)
+                .unwrap();
+            base_token_free
+        }
+        None => 0,
+    };
+    let base_token_returned = base_token_free - base_token_vesting;
+
+    emit!(Settled {
+        auction: open_orders.auction,
+        open_orders: open_orders.this_open_orders,
+        user: open_orders.authority,
+        quote_amount_returned: quote_token_free,
+        base_amount_returned: base_token_returned,
+        base_amount_vesting: base_token_vesting,
+    });
+    (quote_token_free, base_token_returned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::*;
+
+    fn order_history() -> OrderHistory {
+        OrderHistory {
+            bump: 1,
+            auction: Pubkey::default(),
+            side: Side::Bid,
+            quote_amount_returned: 0,
+            base_amount_returned: 0,
+            clearing_price: 0,
+            orders: vec![],
+        }
+    }
+
+    fn settled_bid() -> OpenOrders {
+        OpenOrders {
+            side: Side::Bid,
+            quote_token_free: 300,
+            base_token_free: 700,
+            order_records: vec![OrderRecord {
+                order_id: 1,
+                base_filled: 700,
+                quote_refunded: 300,
+                ..OrderRecord::default()
+            }],
+            ..OpenOrders::default()
+        }
+    }
+
+    #[test]
+    fn settling_returns_the_free_balances_and_records_them() {
+        let mut auction = Auction {
+            clearing_price: 42,
+            num_open_orders: 2,
+            ..Auction::default()
+        };
+        let mut open_orders = settled_bid();
+        let mut order_history = order_history();
+        let returned = settle_open_orders(&mut auction, &mut open_orders, &mut order_history, None);
+        assert_eq!(returned, (300, 700));
+        assert_eq!(auction.num_open_orders, 1);
+        assert_eq!(
+            (open_orders.quote_token_free, open_orders.base_token_free),
+            (0, 0)
+        );
+        assert_eq!(order_history.bump, 1);
+        assert_eq!(order_history.quote_amount_returned, 300);
+        assert_eq!(order_history.base_amount_returned, 700);
+        assert_eq!(order_history.clearing_price, 42);
+        assert_eq!(order_history.orders.len(), 1);
+        assert_eq!(order_history.orders[0].base_filled, 700);
+    }
+
+    #[test]
+    fn order_history_space_fits_its_records() {
+        let mut order_history = order_history();
+        assert_eq!(order_history.try_to_vec().unwrap().len() + 8, ORDER_HISTORY_SPACE);
+        order_history.orders = vec![OrderRecord::default(); 6];
+        assert_eq!(
+            order_history.try_to_vec().unwrap().len() + 8,
+            order_history.space()
+        );
+    }
+
+    #[test]
+    fn vesting_bids_hold_back_their_base() {
+        let mut auction = Auction {
+            num_open_orders: 1,
+            base_vesting_unclaimed: 50,
+            ..Auction::default()
+        };
+        let mut vesting = Vesting {
+            bump: 1,
+            auction: Pubkey::default(),
+            base_amount_vesting: 0,
+            base_amount_claimed: 0,
+        };
+        let returned = settle_open_orders(
+            &mut auction,
+            &mut settled_bid(),
+            &mut order_history(),
+            Some(&mut vesting),
+        );
+        assert_eq!(returned, (300, 0));
+        assert_eq!(vesting.base_amount_vesting, 700);
+        assert_eq!(auction.base_vesting_unclaimed, 750);
+    }
+}
@@ -0,0 +1,354 @@
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::{EventQueueHeader, EVENT_QUEUE_HEADER_LEN};
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::instructions::settle_open_orders;
+use crate::program_accounts::*;
+use crate::token_interface::{get_associated_token_address, transfer_checked, TransferChecked};
+use crate::types::*;
+use crate::utils::realloc_program_account;
+
+// Number of accounts per user in remaining accounts
+const SETTLE_ACCOUNTS_LEN: usize = 6;
+
+#[derive(Accounts)]
+pub struct SettleOpenOrdersBatch<'info> {
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    // AOB Accounts
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.event_queue,
+        owner = crate::ID,
+    )]
+    pub event_queue: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.bids,
+        owner = crate::ID,
+    )]
+    pub bids: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.asks,
+        owner = crate::ID,
+    )]
+    pub asks: UncheckedAccount<'info>,
+    // Token Accounts
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.quote_vault,
+        mut
+    )]
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.base_vault,
+        mut
+    )]
+    pub base_vault: UncheckedAccount<'info>,
+    /// CHECK: The auction's quote mint
+    #[account(address = auction.quote_mint)]
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
+    #[account(address = auction.base_mint)]
+    pub base_mint: UncheckedAccount<'info>,
+    // Programs
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
+    // Plus (user, open_orders, order_history, vesting, user_quote, user_base)
+    // for each user in remaining accounts. The user's wallet is needed to
+    // return the open orders rent and the vesting account to hold their
+    // purchased base if the auction vests. The vesting account is only
+    // checked for bidders in auctions that vest, it doesn't exist otherwise.
+}
+
+impl SettleOpenOrdersBatch<'_> {
+    // Nothing gets settled until every order has been matched and every fill
+    // consumed, or the auction has been cancelled
+    pub fn access_control(&self) -> Result<()> {
+        let auction = self.auction.clone().into_inner();
+        if auction.is_cancelled {
+            return Ok(());
+        }
+        let order_book = OrderBookState::new_safe(
+            &self.bids.to_account_info(),
+            &self.asks.to_account_info(),
+            CALLBACK_INFO_LEN,
+            CALLBACK_ID_LEN,
+        )?;
+        let event_queue_header = {
+            let mut event_queue_data: &[u8] =
+                &self.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+            EventQueueHeader::deserialize(&mut event_queue_data)
+                .unwrap()
+                .check()?
+        };
+
+        if !is_auction_over(&auction, &order_book, &event_queue_header) {
+            return Err(error!(CustomErrors::AuctionNotFinished));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> SettleOpenOrdersBatch<'info> {
+    pub fn transfer_quote_vault(
+        &self,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.quote_vault.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
+            to: to.clone(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+    pub fn transfer_base_vault(
+        &self,
+        to: &AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
+            to: to.clone(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+/// Permissionless crank that settles and closes many open orders accounts at
+/// once, so funds don't sit in the vaults waiting for every user to settle
+pub fn settle_open_orders_batch<'info>(
+    ctx: Context<'_, '_, '_, 'info, SettleOpenOrdersBatch<'info>>,
+) -> Result<()> {
+    if ctx.remaining_accounts.len() % SETTLE_ACCOUNTS_LEN != 0 {
+        return Err(error!(CustomErrors::InvalidSettleAccounts));
+    }
+    let auction_id = ctx.accounts.auction.auction_id;
+    let authority = ctx.accounts.auction.authority;
+    let now = Clock::get()?.unix_timestamp;
+
+    for accounts in ctx.remaining_accounts.chunks(SETTLE_ACCOUNTS_LEN) {
+        let user = &accounts[0];
+        let mut open_orders: Account<OpenOrders> = Account::try_from(&accounts[1])?;
+        let mut order_history: Account<OrderHistory> = Account::try_from(&accounts[2])?;
+        let mut vesting: Option<Account<Vesting>> =
+            match ctx.accounts.auction.needs_vesting_account(open_orders.side) {
+                true => Some(Account::try_from(&accounts[3])?),
+                false => None,
+            };
+        let user_quote = &accounts[4];
+        let user_base = &accounts[5];
+
+        // Same checks the account constraints do in settle_and_close_open_orders
+        let is_pda = |seed: &str, bump: u8, key: Pubkey| {
+            Pubkey::create_program_address(
+                &[
+                    user.key.as_ref(),
+                    seed.as_bytes(),
+                    &auction_id,
+                    authority.as_ref(),
+                    &[bump],
+                ],
+                ctx.program_id,
+            ) == Ok(key)
+        };
+        if !user.is_writable
+            || !is_pda(OPEN_ORDERS, open_orders.bump, open_orders.key())
+            || !is_pda(ORDER_HISTORY, order_history.bump, order_history.key())
+            || matches!(&vesting, Some(vesting) if !is_pda(VESTING, vesting.bump, vesting.key()))
+            || !user_quote.is_writable
+            || !user_base.is_writable
+            || *user_quote.key
+                != get_associated_token_address(
+                    user.key,
+                    &ctx.accounts.auction.quote_mint,
+                    &ctx.accounts.auction.quote_token_program,
+                )
+            || *user_base.key
+                != get_associated_token_address(
+                    user.key,
+                    &ctx.accounts.auction.base_mint,
+                    &ctx.accounts.auction.base_token_program,
+                )
+        {
+            return Err(error!(CustomErrors::InvalidSettleAccounts));
+        }
+        // Users who never came back to reveal would otherwise block settling,
+        // and with it sweeping the penalties and closing the auction
+        if ctx.accounts.auction.is_cancelled || ctx.accounts.auction.end_decryption_phase <= now {
+            release_encrypted_orders(&ctx.accounts.auction, &mut open_orders, now);
+        }
+        can_settle_open_orders(&open_orders)?;
+
+        let (quote_token_free, base_token_free) = settle_open_orders(
+            &mut ctx.accounts.auction,
+            &mut open_orders,
+            &mut order_history,
+            vesting.as_deref_mut(),
+        );
+        realloc_program_account(&accounts[2], &accounts[1], order_history.space())?;
+        order_history.exit(ctx.program_id)?;
+        if let Some(vesting) = vesting {
+            vesting.exit(ctx.program_id)?;
+        }
+        open_orders.close(user.clone())?;
+
+        if quote_token_free > 0 {
+            transfer_checked(
+                ctx.accounts
+                    .transfer_quote_vault(user_quote)
+                    .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                ctx.accounts.auction.quote_lots_to_atoms(quote_token_free),
+            )?;
+        }
+        if base_token_free > 0 {
+            transfer_checked(
+                ctx.accounts
+                    .transfer_base_vault(user_base)
+                    .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+                ctx.accounts.auction.base_lots_to_atoms(base_token_free),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Unlocks the deposits of encrypted orders that can no longer be decrypted
+/// so they get settled with the rest of the free balance. Same refund as
+/// cancelling them, the unrevealed penalty stays in the vault.
+fn release_encrypted_orders(auction: &Auction, open_orders: &mut OpenOrders, now: i64) {
+    for order in std::mem::take(&mut open_orders.encrypted_orders) {
+        let refund = order
+            .token_qty
+            .checked_sub(auction.unrevealed_penalty(order.token_qty, now))
+            .unwrap();
+        let (base_qty, quote_qty) = match open_orders.side {
+            Side::Ask => {
+                open_orders.base_token_locked = open_orders
+                    .base_token_locked
+                    .checked_sub(order.token_qty)
+                    .unwrap();
+                open_orders.base_token_free =
+                    open_orders.base_token_free.checked_add(refund).unwrap();
+                (order.token_qty, 0)
+            }
+            Side::Bid => {
+                open_orders.quote_token_locked = open_orders
+                    .quote_token_locked
+                    .checked_sub(order.token_qty)
+                    .unwrap();
+                open_orders.quote_token_free =
+                    open_orders.quote_token_free.checked_add(refund).unwrap();
+                (0, order.token_qty)
+            }
+        };
+        open_orders.num_orders = open_orders.num_orders.checked_sub(1).unwrap();
+        emit!(OrderCancelled {
+            auction: open_orders.auction,
+            open_orders: open_orders.this_open_orders,
+            user: open_orders.authority,
+            side: open_orders.side,
+            order_id: 0,
+            is_encrypted: true,
+            base_qty,
+            quote_qty,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commit_reveal_auction() -> Auction {
+        Auction {
+            end_order_phase: 1_000,
+            is_commit_reveal: true,
+            unrevealed_penalty_bps: 1_000,
+            ..Auction::default()
+        }
+    }
+
+    fn open_orders_with_commitments(side: Side, token_qtys: &[u64]) -> OpenOrders {
+        let locked: u64 = token_qtys.iter().sum();
+        let mut open_orders = OpenOrders {
+            side,
+            num_orders: token_qtys.len() as u8,
+            encrypted_orders: token_qtys
+                .iter()
+                .map(|token_qty| EncryptedOrder {
+                    nonce: vec![0; 24],
+                    cipher_text: vec![0; 32],
+                    token_qty: *token_qty,
+                })
+                .collect(),
+            ..OpenOrders::default()
+        };
+        match side {
+            Side::Bid => open_orders.quote_token_locked = locked,
+            Side::Ask => open_orders.base_token_locked = locked,
+        }
+        open_orders
+    }
+
+    #[test]
+    fn leftover_commitments_are_refunded_less_the_penalty() {
+        let auction = commit_reveal_auction();
+        let mut open_orders = open_orders_with_commitments(Side::Bid, &[1_000, 500]);
+        release_encrypted_orders(&auction, &mut open_orders, 2_000);
+        assert!(open_orders.encrypted_orders.is_empty());
+        assert_eq!(open_orders.num_orders, 0);
+        assert_eq!(open_orders.quote_token_locked, 0);
+        assert_eq!(open_orders.quote_token_free, 900 + 450);
+        assert!(can_settle_open_orders(&open_orders).is_ok());
+    }
+
+    #[test]
+    fn cancelled_auctions_refund_commitments_in_full() {
+        let auction = Auction {
+            is_cancelled: true,
+            ..commit_reveal_auction()
+        };
+        let mut open_orders = open_orders_with_commitments(Side::Ask, &[1_000]);
+        release_encrypted_orders(&auction, &mut open_orders, 2_000);
+        assert_eq!(open_orders.base_token_locked, 0);
+        assert_eq!(open_orders.base_token_free, 1_000);
+        assert!(can_settle_open_orders(&open_orders).is_ok());
+    }
+
+    #[test]
+    fn encrypted_auctions_refund_undecrypted_orders_in_full() {
+        let auction = Auction {
+            is_commit_reveal: false,
+            ..commit_reveal_auction()
+        };
+        let mut open_orders = open_orders_with_commitments(Side::Bid, &[1_000]);
+        release_encrypted_orders(&auction, &mut open_orders, 2_000);
+        assert_eq!(open_orders.quote_token_free, 1_000);
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::encryption::{combine_key_shares, verify_key_share};
+use crate::error::CustomErrors;
+use crate::events::*;
+use crate::program_accounts::*;
+use crate::types::*;
+
+#[derive(Accounts)]
+pub struct SubmitKeyShare<'info> {
+    pub member: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+}
+
+impl SubmitKeyShare<'_> {
+    pub fn access_control(&self, share: &[u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+
+        // Shares are only published once no more orders can be placed
+        if !is_decryption_phase_active(clock, &auction) {
+            return Err(error!(CustomErrors::DecryptionPhaseNotActive));
+        }
+        let member_idx = auction
+            .committee
+            .iter()
+            .position(|member| *member == self.member.key())
+            .ok_or_else(|| error!(CustomErrors::NotACommitteeMember))?;
+        if auction
+            .key_shares
+            .iter()
+            .any(|key_share| key_share.member_idx as usize == member_idx)
+        {
+            return Err(error!(CustomErrors::KeyShareAlreadySubmitted));
+        }
+        if !verify_key_share(share, &auction.committee_public_shares[member_idx]) {
+            return Err(error!(CustomErrors::InvalidKeyShare));
+        }
+
+        Ok(())
+    }
+}
+
+/// Publishes a committee member's share of the combined secret key. Once the
+/// threshold is reached the key is rebuilt and decrypt_order can use it.
+pub fn submit_key_share(ctx: Context<SubmitKeyShare>, share: [u8; 32]) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let auction = &mut ctx.accounts.auction;
+    let member_idx = auction
+        .committee
+        .iter()
+        .position(|this_member| *this_member == member)
+        .unwrap();
+    auction.key_shares.push(KeyShare {
+        member_idx: member_idx as u8,
+        share,
+    });
+
+    // The public shares were checked to interpolate to nacl_pubkey when the
+    // auction was created, so the first threshold verified shares rebuild it
+    let threshold = auction.committee_threshold as usize;
+    if auction.key_shares.len() == threshold {
+        auction.committee_secret_key =
+            combine_key_shares(&auction.key_shares[..threshold]).to_bytes();
+    }
+
+    emit!(KeyShareSubmitted {
+        auction: auction.key(),
+        member,
+        num_key_shares: auction.key_shares.len() as u8,
+    });
+
+    Ok(())
+}
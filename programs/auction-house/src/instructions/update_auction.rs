@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+use crate::types::*;
+
+#[derive(Accounts)]
+pub struct UpdateAuction<'info> {
+    pub auctioneer: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+}
+
+impl UpdateAuction<'_> {
+    // Open orders accounts can only be created during the order phase, so
+    // before it starts there aren't any users relying on the old settings
+    pub fn access_control(&self, args: &InitAuctionArgs) -> Result<()> {
+        let clock = Clock::get()?;
+        let auction = self.auction.clone().into_inner();
+
+        if auction.start_order_phase <= clock.unix_timestamp {
+            return Err(error!(CustomErrors::AuctionAlreadyStarted));
+        }
+        if auction.is_cancelled {
+            return Err(error!(CustomErrors::AuctionCancelled));
+        }
+        // Shouldn't happen given the check above, but any open orders account
+        // would have been created under the settings being replaced
+        if auction.num_open_orders > 0 {
+            return Err(error!(CustomErrors::AuctionHasOpenOrders));
+        }
+        if auction.auction_id != args.auction_id {
+            return Err(error!(CustomErrors::AuctionIdCannotChange));
+        }
+        if auction.committee.len() != args.committee.len() {
+            return Err(error!(CustomErrors::CommitteeSizeCannotChange));
+        }
+        // The deposited supply is already sitting in the vault
+        if auction.has_deposited_fixed_supply
+            && (auction.fixed_base_supply != args.fixed_base_supply
+                || auction.base_lot_size != args.base_lot_size)
+        {
+            return Err(error!(CustomErrors::FixedSupplyAlreadyDeposited));
+        }
+        validate_auction_args(clock, args)?;
+
+        Ok(())
+    }
+}
+
+pub fn update_auction(ctx: Context<UpdateAuction>, args: &InitAuctionArgs) -> Result<()> {
+    ctx.accounts.auction.update_settings(args);
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use agnostic_orderbook::orderbook::OrderBookState;
+use agnostic_orderbook::state::{EventQueueHeader, EVENT_QUEUE_HEADER_LEN};
+
+use crate::access_controls::*;
+use crate::consts::*;
+use crate::error::CustomErrors;
+use crate::program_accounts::*;
+use crate::token_interface::{get_associated_token_address, transfer_checked, TransferChecked};
+
+#[derive(Accounts)]
+pub struct WithdrawProceeds<'info> {
+    pub auctioneer: Signer<'info>,
+    // Program Accounts
+    #[account(
+        seeds = [AUCTION.as_bytes(), &auction.auction_id, auctioneer.key().as_ref()],
+        bump = auction.bump,
+        mut
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+    // AOB Accounts
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.event_queue,
+        owner = crate::ID,
+    )]
+    pub event_queue: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.bids,
+        owner = crate::ID,
+    )]
+    pub bids: UncheckedAccount<'info>,
+    /// CHECK: This should be owned by the program
+    #[account(
+        address = auction.asks,
+        owner = crate::ID,
+    )]
+    pub asks: UncheckedAccount<'info>,
+    // Token accounts
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [QUOTE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.quote_vault,
+        mut
+    )]
+    pub quote_vault: UncheckedAccount<'info>,
+    /// CHECK: Created by init_auction
+    #[account(
+        seeds = [BASE_VAULT.as_bytes(), &auction.auction_id, auction.authority.as_ref()],
+        bump = auction.bumps.base_vault,
+        mut
+    )]
+    pub base_vault: UncheckedAccount<'info>,
+    /// CHECK: The auction's quote mint
+    #[account(address = auction.quote_mint)]
+    pub quote_mint: UncheckedAccount<'info>,
+    /// CHECK: The auction's base mint
+    #[account(address = auction.base_mint)]
+    pub base_mint: UncheckedAccount<'info>,
+    /// CHECK: The auctioneer's associated token account, the token program checks the rest
+    #[account(
+        address = get_associated_token_address(&auctioneer.key(), &auction.quote_mint, &auction.quote_token_program),
+        mut
+    )]
+    pub auctioneer_quote: UncheckedAccount<'info>,
+    /// CHECK: The auctioneer's associated token account, the token program checks the rest
+    #[account(
+        address = get_associated_token_address(&auctioneer.key(), &auction.base_mint, &auction.base_token_program),
+        mut
+    )]
+    pub auctioneer_base: UncheckedAccount<'info>,
+    // Programs
+    /// CHECK: Whichever token program owns the quote mint
+    #[account(address = auction.quote_token_program)]
+    pub quote_token_program: UncheckedAccount<'info>,
+    /// CHECK: Whichever token program owns the base mint
+    #[account(address = auction.base_token_program)]
+    pub base_token_program: UncheckedAccount<'info>,
+}
+
+impl WithdrawProceeds<'_> {
+    pub fn access_control(&self) -> Result<()> {
+        let auction = self.auction.clone().into_inner();
+        let order_book = OrderBookState::new_safe(
+            &self.bids.to_account_info(),
+            &self.asks.to_account_info(),
+            CALLBACK_INFO_LEN,
+            CALLBACK_ID_LEN,
+        )?;
+        let event_queue_header = {
+            let mut event_queue_data: &[u8] =
+                &self.event_queue.data.borrow()[0..EVENT_QUEUE_HEADER_LEN];
+            EventQueueHeader::deserialize(&mut event_queue_data)
+                .unwrap()
+                .check()?
+        };
+
+        if !auction.is_fixed_supply() || !auction.has_deposited_fixed_supply {
+            return Err(error!(CustomErrors::NotFixedSupplyAuction));
+        }
+        if auction.has_withdrawn_proceeds {
+            return Err(error!(CustomErrors::ProceedsAlreadyWithdrawn));
+        }
+        // Proceeds are only final once every fill has been consumed
+        if !is_auction_over(&auction, &order_book, &event_queue_header) {
+            return Err(error!(CustomErrors::AuctionNotFinished));
+        }
+
+        Ok(())
+    }
+}
+
+impl<'info> WithdrawProceeds<'info> {
+    pub fn transfer_base_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.base_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.base_vault.to_account_info(),
+            mint: self.base_mint.to_account_info(),
+            to: self.auctioneer_base.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+    pub fn transfer_quote_vault(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let program = self.quote_token_program.to_account_info();
+        let accounts = TransferChecked {
+            from: self.quote_vault.to_account_info(),
+            mint: self.quote_mint.to_account_info(),
+            to: self.auctioneer_quote.to_account_info(),
+            authority: self.auction.to_account_info(),
+        };
+        CpiContext::new(program, accounts)
+    }
+}
+
+pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
+    let auction = &mut ctx.accounts.auction;
+    let quote_proceeds = auction.quote_proceeds;
+    let unsold_base = auction
+        .fixed_base_supply
+        .checked_sub(auction.base_sold)
+        .unwrap();
+    auction.has_withdrawn_proceeds = true;
+
+    if quote_proceeds > 0 {
+        transfer_checked(
+            ctx.accounts
+                .transfer_quote_vault()
+                .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+            ctx.accounts.auction.quote_lots_to_atoms(quote_proceeds),
+        )?;
+    }
+    if unsold_base > 0 {
+        transfer_checked(
+            ctx.accounts
+                .transfer_base_vault()
+                .with_signer(&[auction_seeds!(ctx.accounts.auction)]),
+            ctx.accounts.auction.base_lots_to_atoms(unsold_base),
+        )?;
+    }
+
+    Ok(())
+}
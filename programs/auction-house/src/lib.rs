@@ -5,10 +5,14 @@ use types::*;
 
 mod access_controls;
 mod consts;
+mod encryption;
 mod error;
+mod events;
 mod instructions;
 pub mod program_accounts;
+mod token_interface;
 pub mod types; // `accounts` is already in use in this file, so we call the folder `program_accounts`
+mod utils;
 
 declare_id!("FpuKSiZ5j5Qu68X2QB2Ji2BcYpPptSX2Pmv7EFAcZiF2");
 
@@ -21,13 +25,25 @@ pub mod auction_house {
         instructions::init_auction(ctx, &args)
     }
 
-    #[access_control(ctx.accounts.access_control(max_orders))]
+    #[access_control(ctx.accounts.access_control(&args))]
+    pub fn update_auction(ctx: Context<UpdateAuction>, args: InitAuctionArgs) -> Result<()> {
+        instructions::update_auction(ctx, &args)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn deposit_fixed_supply(ctx: Context<DepositFixedSupply>) -> Result<()> {
+        instructions::deposit_fixed_supply(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control(side, max_orders, &allowlist_proof, max_base_allocation))]
     pub fn init_open_orders(
         ctx: Context<InitOpenOrders>,
         side: Side,
         max_orders: u8,
+        allowlist_proof: Vec<[u8; 32]>,
+        max_base_allocation: u64,
     ) -> Result<()> {
-        instructions::init_open_orders(ctx, side, max_orders)
+        instructions::init_open_orders(ctx, side, max_orders, max_base_allocation)
     }
 
     #[access_control(ctx.accounts.access_control_new_order(limit_price, max_base_qty))]
@@ -35,12 +51,41 @@ pub mod auction_house {
         instructions::new_order(ctx, limit_price, max_base_qty)
     }
 
+    #[access_control(ctx.accounts.access_control_new_quote_budget_order(limit_price, max_quote_qty))]
+    pub fn new_quote_budget_order(
+        ctx: Context<NewOrder>,
+        limit_price: u64,
+        max_quote_qty: u64,
+    ) -> Result<()> {
+        instructions::new_quote_budget_order(ctx, limit_price, max_quote_qty)
+    }
+
+    #[access_control(ctx.accounts.access_control_new_orders(&orders))]
+    pub fn new_orders(ctx: Context<NewOrder>, orders: Vec<OrderArgs>) -> Result<()> {
+        instructions::new_orders(ctx, orders)
+    }
+
     #[access_control(ctx.accounts.access_control_cancel_order(&order_id))]
     pub fn cancel_order(ctx: Context<NewOrder>, order_id: u128) -> Result<()> {
         instructions::cancel_order(ctx, order_id)
     }
 
-    #[access_control(ctx.accounts.access_control_new_encrypted_order(&nacl_pubkey))]
+    #[access_control(ctx.accounts.access_control_cancel_all_orders())]
+    pub fn cancel_all_orders(ctx: Context<NewOrder>) -> Result<()> {
+        instructions::cancel_all_orders(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control_amend_order(&order_id, limit_price, max_base_qty))]
+    pub fn amend_order(
+        ctx: Context<NewOrder>,
+        order_id: u128,
+        limit_price: u64,
+        max_base_qty: u64,
+    ) -> Result<()> {
+        instructions::amend_order(ctx, order_id, limit_price, max_base_qty)
+    }
+
+    #[access_control(ctx.accounts.access_control_new_encrypted_order(&nacl_pubkey, &cipher_text))]
     pub fn new_encrypted_order(
         ctx: Context<NewEncryptedOrder>,
         token_qty: u64,
@@ -56,11 +101,40 @@ pub mod auction_house {
         instructions::cancel_encrypted_order(ctx, order_idx)
     }
 
+    #[access_control(ctx.accounts.access_control(&share))]
+    pub fn submit_key_share(ctx: Context<SubmitKeyShare>, share: [u8; 32]) -> Result<()> {
+        instructions::submit_key_share(ctx, share)
+    }
+
     #[access_control(ctx.accounts.access_control())]
     pub fn decrypt_order(ctx: Context<DecryptOrder>, shared_key: Vec<u8>) -> Result<()> {
         instructions::decrypt_order(ctx, shared_key)
     }
 
+    #[access_control(ctx.accounts.access_control(&secret_key))]
+    pub fn reveal_own_orders(ctx: Context<RevealOwnOrders>, secret_key: [u8; 32]) -> Result<()> {
+        instructions::reveal_own_orders(ctx, secret_key)
+    }
+
+    #[access_control(ctx.accounts.access_control_reveal_order(order_idx, &price_and_quantity, &salt))]
+    pub fn reveal_order(
+        ctx: Context<RevealOwnOrders>,
+        order_idx: u8,
+        price_and_quantity: Vec<u8>,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_order(ctx, order_idx, price_and_quantity)
+    }
+
+    #[access_control(ctx.accounts.access_control(reserve_price, &salt))]
+    pub fn reveal_reserve_price(
+        ctx: Context<RevealReservePrice>,
+        reserve_price: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_reserve_price(ctx, reserve_price)
+    }
+
     #[access_control(ctx.accounts.access_control())]
     pub fn calculate_clearing_price(
         ctx: Context<CalculateClearingPrice>,
@@ -87,8 +161,58 @@ pub mod auction_house {
         instructions::settle_and_close_open_orders(ctx)
     }
 
+    #[access_control(ctx.accounts.access_control())]
+    pub fn settle_open_orders_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleOpenOrdersBatch<'info>>,
+    ) -> Result<()> {
+        instructions::settle_open_orders_batch(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn abort_auction(ctx: Context<AbortAuction>) -> Result<()> {
+        instructions::abort_auction(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
+        instructions::withdraw_proceeds(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+        instructions::sweep_dust(ctx)
+    }
+
     #[access_control(ctx.accounts.access_control())]
     pub fn close_aob_accounts(ctx: Context<CloseAobAccounts>) -> Result<()> {
         instructions::close_aob_accounts(ctx)
     }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        instructions::close_auction(ctx)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn close_order_history(
+        ctx: Context<CloseOrderHistory>,
+        auction_id: [u8; 10],
+        auctioneer: Pubkey,
+    ) -> Result<()> {
+        instructions::close_order_history(ctx, auction_id, auctioneer)
+    }
+
+    #[access_control(ctx.accounts.access_control())]
+    pub fn close_vesting(
+        ctx: Context<CloseVesting>,
+        auction_id: [u8; 10],
+        auctioneer: Pubkey,
+    ) -> Result<()> {
+        instructions::close_vesting(ctx, auction_id, auctioneer)
+    }
 }
@@ -13,10 +13,24 @@ pub struct Auction {
     pub start_order_phase: i64,
     pub end_order_phase: i64,
     pub end_decryption_phase: i64,
+    pub soft_close_window: i64,
+    pub soft_close_extension: i64,
+    pub max_soft_close_extension: i64,
+    pub total_soft_close_extension: i64,
     pub are_asks_encrypted: bool,
     pub are_bids_encrypted: bool,
     pub nacl_pubkey: Vec<u8>,
-    // pub final_price_type: FinalPriceTypes,
+    pub is_commit_reveal: bool,
+    pub unrevealed_penalty_bps: u16,
+    pub committee: Vec<Pubkey>,
+    pub committee_threshold: u8,
+    pub committee_public_shares: Vec<[u8; 32]>,
+    pub key_shares: Vec<KeyShare>,
+    // Rebuilt from the key shares, all zeroes until enough have been submitted
+    pub committee_secret_key: [u8; 32],
+    pub final_price_type: FinalPriceTypes,
+    pub fill_allocation_type: FillAllocationTypes,
+    pub is_pay_as_bid: bool,
     // Orderbook details
     pub event_queue: Pubkey,
     pub bids: Pubkey,
@@ -25,8 +39,23 @@ pub struct Auction {
     pub base_mint: Pubkey,
     pub quote_vault: Pubkey,
     pub base_vault: Pubkey,
+    // Either the token program or token-2022, whichever owns the mint
+    pub quote_token_program: Pubkey,
+    pub base_token_program: Pubkey,
+    pub base_lot_size: u64,
+    pub quote_lot_size: u64,
     pub min_base_order_size: u64,
     pub tick_size: u64,
+    pub max_base_per_user: u64,
+    pub allowlist_root: [u8; 32],
+    pub reserve_price: u64,
+    pub reserve_price_commitment: [u8; 32],
+    pub min_base_matched: u64,
+    pub min_quote_raised: u64,
+    pub fixed_base_supply: u64,
+    pub has_deposited_fixed_supply: bool,
+    pub vesting_cliff: i64,
+    pub vesting_duration: i64,
     // Intermediate information while matching the orderbook
     pub ask_search_stack_depth: u8,
     pub bid_search_stack_depth: u8,
@@ -37,14 +66,119 @@ pub struct Auction {
     pub current_bid_quantity_filled: u64,
     pub current_ask_quantity_filled: u64,
     pub total_quantity_filled_so_far: u64,
+    pub current_bid_level_quantity: u64,
+    pub current_ask_level_quantity: u64,
+    pub filled_bid_levels_quantity: u64,
+    pub filled_ask_levels_quantity: u64,
+    // Set once prices cross, the rest of the marginal price levels are then
+    // totalled up over as many calls as it takes
+    pub has_crossed: bool,
+    pub is_bid_level_complete: bool,
+    pub is_ask_level_complete: bool,
+    // None when every bid on the book wins
+    pub first_losing_bid_price: Option<u64>,
     // Details once the auction clearing price has been found
     pub has_found_clearing_price: bool,
+    pub has_failed: bool,
     pub total_quantity_matched: u64,
     pub remaining_ask_fills: u64,
     pub remaining_bid_fills: u64,
     pub final_bid_price: u64,
     pub final_ask_price: u64,
     pub clearing_price: u64,
+    // Quantity at the marginal levels still to be matched
+    pub marginal_bid_quantity: u64,
+    pub marginal_ask_quantity: u64,
+    // Proceeds owed to the auctioneer in fixed supply auctions
+    pub base_sold: u64,
+    pub quote_proceeds: u64,
+    pub has_withdrawn_proceeds: bool,
+    // Set by the auctioneer to call off the auction, every order gets refunded
+    pub is_cancelled: bool,
+    // Leftovers can only be swept once every open orders account has settled
+    // and vested base has been claimed
+    pub num_open_orders: u32,
+    pub base_vesting_unclaimed: u64,
+}
+
+impl Auction {
+    pub fn is_fixed_supply(&self) -> bool {
+        self.fixed_base_supply > 0
+    }
+
+    // Everything the auctioneer can still change before the order phase starts
+    pub fn update_settings(&mut self, args: &InitAuctionArgs) {
+        self.start_order_phase = args.start_order_phase;
+        self.end_order_phase = args.end_order_phase;
+        self.end_decryption_phase = args.end_decryption_phase;
+        self.soft_close_window = args.soft_close_window;
+        self.soft_close_extension = args.soft_close_extension;
+        self.max_soft_close_extension = args.max_soft_close_extension;
+        self.are_asks_encrypted = args.are_asks_encrypted;
+        self.are_bids_encrypted = args.are_bids_encrypted;
+        self.nacl_pubkey = args.nacl_pubkey.clone();
+        self.is_commit_reveal = args.is_commit_reveal;
+        self.unrevealed_penalty_bps = args.unrevealed_penalty_bps;
+        self.committee = args.committee.clone();
+        self.committee_threshold = args.committee_threshold;
+        self.committee_public_shares = args.committee_public_shares.clone();
+        self.final_price_type = args.final_price_type;
+        self.fill_allocation_type = args.fill_allocation_type;
+        self.is_pay_as_bid = args.is_pay_as_bid;
+        self.base_lot_size = args.base_lot_size;
+        self.quote_lot_size = args.quote_lot_size;
+        self.min_base_order_size = args.min_base_order_size;
+        self.tick_size = args.tick_size;
+        self.max_base_per_user = args.max_base_per_user;
+        self.allowlist_root = args.allowlist_root;
+        self.reserve_price = args.reserve_price;
+        self.reserve_price_commitment = args.reserve_price_commitment;
+        self.min_base_matched = args.min_base_matched;
+        self.min_quote_raised = args.min_quote_raised;
+        self.fixed_base_supply = args.fixed_base_supply;
+        self.vesting_cliff = args.vesting_cliff;
+        self.vesting_duration = args.vesting_duration;
+    }
+
+    // Order book quantities and open orders balances are all in lots, token
+    // amounts only get converted to atoms when they're transferred
+    pub fn base_lots_to_atoms(&self, base_lots: u64) -> u64 {
+        base_lots.checked_mul(self.base_lot_size).unwrap()
+    }
+
+    pub fn quote_lots_to_atoms(&self, quote_lots: u64) -> u64 {
+        quote_lots.checked_mul(self.quote_lot_size).unwrap()
+    }
+
+    pub fn has_vesting(&self) -> bool {
+        self.vesting_duration > 0
+    }
+
+    // Only purchased base vests, so asks never need a vesting account
+    pub fn needs_vesting_account(&self, side: Side) -> bool {
+        self.has_vesting() && side == Side::Bid
+    }
+
+    // Cliff and linear vesting, both measured from the end of the decryption phase
+    pub fn vested_amount(&self, total_amount: u64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.end_decryption_phase);
+        if elapsed < self.vesting_cliff {
+            return 0;
+        }
+        if elapsed >= self.vesting_duration {
+            return total_amount;
+        }
+        ((total_amount as u128) * (elapsed as u128) / (self.vesting_duration as u128)) as u64
+    }
+
+    // Commitments still unrevealed after the order phase are only partially
+    // refunded, the rest is left in the vault for the auctioneer
+    pub fn unrevealed_penalty(&self, token_qty: u64, now: i64) -> u64 {
+        if !self.is_commit_reveal || self.is_cancelled || now < self.end_order_phase {
+            return 0;
+        }
+        ((token_qty as u128) * (self.unrevealed_penalty_bps as u128) / 10_000) as u64
+    }
 }
 
 #[macro_export]
@@ -61,15 +195,87 @@ macro_rules! auction_seeds {
 
 pub use auction_seeds;
 
-// Maybe one day we might introduce an option like this
-// #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-// pub enum FinalPriceTypes {
-//     BestBid,
-//     Midpoint,
-// }
-
-// impl Default for FinalPriceTypes {
-//     fn default() -> Self {
-//         FinalPriceTypes::BestBid
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vesting_auction() -> Auction {
+        Auction {
+            end_decryption_phase: 1_000,
+            vesting_cliff: 100,
+            vesting_duration: 400,
+            ..Auction::default()
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let auction = vesting_auction();
+        assert_eq!(auction.vested_amount(1_000, 0), 0);
+        assert_eq!(auction.vested_amount(1_000, 1_000), 0);
+        assert_eq!(auction.vested_amount(1_000, 1_099), 0);
+    }
+
+    #[test]
+    fn vesting_is_linear_from_the_end_of_decryption_once_past_the_cliff() {
+        let auction = vesting_auction();
+        assert_eq!(auction.vested_amount(1_000, 1_100), 250);
+        assert_eq!(auction.vested_amount(1_000, 1_200), 500);
+        // Rounded down
+        assert_eq!(auction.vested_amount(999, 1_101), 252);
+    }
+
+    #[test]
+    fn everything_vests_once_the_duration_is_over() {
+        let auction = vesting_auction();
+        assert_eq!(auction.vested_amount(1_000, 1_400), 1_000);
+        assert_eq!(auction.vested_amount(u64::MAX, i64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn cliff_as_long_as_the_duration_vests_all_at_once() {
+        let auction = Auction {
+            vesting_cliff: 400,
+            ..vesting_auction()
+        };
+        assert_eq!(auction.vested_amount(1_000, 1_399), 0);
+        assert_eq!(auction.vested_amount(1_000, 1_400), 1_000);
+    }
+
+    fn commit_reveal_auction() -> Auction {
+        Auction {
+            end_order_phase: 1_000,
+            is_commit_reveal: true,
+            unrevealed_penalty_bps: 2_500,
+            ..Auction::default()
+        }
+    }
+
+    #[test]
+    fn unrevealed_commitments_lose_the_penalty_after_the_order_phase() {
+        let auction = commit_reveal_auction();
+        assert_eq!(auction.unrevealed_penalty(1_000, 1_000), 250);
+        // Rounded down, in the user's favour
+        assert_eq!(auction.unrevealed_penalty(3, 1_000), 0);
+    }
+
+    #[test]
+    fn no_penalty_while_orders_can_still_be_cancelled() {
+        let auction = commit_reveal_auction();
+        assert_eq!(auction.unrevealed_penalty(1_000, 999), 0);
+    }
+
+    #[test]
+    fn no_penalty_outside_commit_reveal_or_once_cancelled() {
+        let auction = Auction {
+            is_commit_reveal: false,
+            ..commit_reveal_auction()
+        };
+        assert_eq!(auction.unrevealed_penalty(1_000, 1_000), 0);
+        let auction = Auction {
+            is_cancelled: true,
+            ..commit_reveal_auction()
+        };
+        assert_eq!(auction.unrevealed_penalty(1_000, 1_000), 0);
+    }
+}
@@ -1,7 +1,9 @@
 pub use auction::*;
 pub use open_orders::*;
 pub use order_history::*;
+pub use vesting::*;
 
 mod auction;
 mod open_orders;
 mod order_history;
+mod vesting;
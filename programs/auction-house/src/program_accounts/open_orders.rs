@@ -29,6 +29,12 @@ pub struct OpenOrders {
     pub base_token_free: u64,
     pub num_orders: u8,
     pub orders: Vec<u128>,
+    pub order_records: Vec<OrderRecord>,
+    // Tracked against the auction's max base per user
+    // and the allocation from the allowlist, if any
+    pub max_base_allocation: u64,
+    pub base_qty_ordered: u64,
+    pub base_qty_filled: u64,
 }
 
 impl OpenOrders {
@@ -41,18 +47,29 @@ impl OpenOrders {
         Ok(idx)
     }
 
+    pub fn find_order_record(&mut self, order_id: &u128) -> Result<&mut OrderRecord> {
+        self.order_records
+            .iter_mut()
+            .find(|record| record.order_id == *order_id)
+            .ok_or_else(|| error!(CustomErrors::OrderIdNotFound))
+    }
+
+    pub fn remove_order_record(&mut self, order_id: &u128) {
+        self.order_records.retain(|record| record.order_id != *order_id);
+    }
+
     pub fn new_order_params(
         &self,
         limit_price: u64,
         max_base_qty: u64,
-        // max_quote_qty: u64,
+        max_quote_qty: u64,
     ) -> Params {
         Params {
             max_base_qty,
-            max_quote_qty: u64::MAX,
+            max_quote_qty,
             limit_price,
             side: AobSide::from(self.side),
-            callback_info: self.this_open_orders.to_bytes().to_vec(),
+            callback_info: [self.this_open_orders.as_ref(), &[0; 8]].concat(),
             post_only: true,
             post_allowed: true,
             // self trade behaviour is ignored, this is a vestigial argument
@@ -2,6 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::types::*;
 
+// Discriminator plus the fields below with no order records, the account is
+// grown to fit the records when the user settles
+pub const ORDER_HISTORY_SPACE: usize = 8 + 1 + 32 + 1 + 8 + 8 + 8 + 4;
+pub const ORDER_RECORD_SPACE: usize = 16 + 8 * 6;
+
 #[account]
 pub struct OrderHistory {
     pub bump: u8,
@@ -9,4 +14,12 @@ pub struct OrderHistory {
     pub side: Side,
     pub quote_amount_returned: u64,
     pub base_amount_returned: u64,
+    pub clearing_price: u64,
+    pub orders: Vec<OrderRecord>,
+}
+
+impl OrderHistory {
+    pub fn space(&self) -> usize {
+        ORDER_HISTORY_SPACE + ORDER_RECORD_SPACE * self.orders.len()
+    }
 }
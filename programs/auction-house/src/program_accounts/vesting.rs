@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+// Discriminator plus the fields below
+pub const VESTING_SPACE: usize = 8 + 1 + 32 + 8 + 8;
+
+#[account]
+pub struct Vesting {
+    pub bump: u8,
+    pub auction: Pubkey,
+    pub base_amount_vesting: u64,
+    pub base_amount_claimed: u64,
+}
@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::associated_token;
+use anchor_spl::token;
+
+use spl_token_2022::extension::transfer_fee::{
+    TransferFee, TransferFeeConfig, MAX_FEE_BASIS_POINTS,
+};
+use spl_token_2022::extension::{ExtensionType, StateWithExtensions};
+use spl_token_2022::state::{Account as TokenAccount, Mint};
+
+use crate::error::CustomErrors;
+use crate::utils::create_pda_account;
+
+// Mints, vaults and user token accounts can belong to either the original
+// token program or token-2022. Anchor's token account types only accept the
+// original program, so those accounts come in unchecked and go through here.
+
+pub fn is_token_program(key: &Pubkey) -> bool {
+    *key == token::ID || *key == spl_token_2022::ID
+}
+
+/// Same derivation as the associated token program, the token program is part of the seeds
+pub fn get_associated_token_address(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &associated_token::ID,
+    )
+    .0
+}
+
+pub fn token_account(info: &AccountInfo) -> Result<TokenAccount> {
+    if !is_token_program(info.owner) {
+        return Err(error!(CustomErrors::InvalidTokenProgram));
+    }
+    let data = info.try_borrow_data()?;
+    Ok(StateWithExtensions::<TokenAccount>::unpack(&data)?.base)
+}
+
+/// Checks the mint belongs to one of the token programs and doesn't use any
+/// extensions the auction can't work with. Transfer fees are covered by the
+/// depositor, see `amount_with_transfer_fee`.
+pub fn validate_mint(info: &AccountInfo) -> Result<()> {
+    if !is_token_program(info.owner) {
+        return Err(error!(CustomErrors::InvalidTokenProgram));
+    }
+    let data = info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    for extension_type in mint.get_extension_types()? {
+        match extension_type {
+            ExtensionType::TransferFeeConfig | ExtensionType::MintCloseAuthority => {}
+            _ => return Err(error!(CustomErrors::UnsupportedMintExtension)),
+        }
+    }
+    Ok(())
+}
+
+pub fn has_transfer_fee(mint_info: &AccountInfo) -> Result<bool> {
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    Ok(mint.get_extension::<TransferFeeConfig>().is_ok())
+}
+
+/// How much has to be sent for `amount` to arrive once the mint's transfer fee
+/// is withheld, so that what gets locked in a vault is exactly what's recorded.
+pub fn amount_with_transfer_fee(mint_info: &AccountInfo, amount: u64) -> Result<u64> {
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?;
+    let transfer_fee = match mint.get_extension::<TransferFeeConfig>() {
+        Ok(transfer_fee_config) => *transfer_fee_config.get_epoch_fee(Clock::get()?.epoch),
+        Err(_) => return Ok(amount),
+    };
+    amount_before_fee(&transfer_fee, amount)
+}
+
+fn amount_before_fee(transfer_fee: &TransferFee, amount: u64) -> Result<u64> {
+    let fee_bps = u16::from(transfer_fee.transfer_fee_basis_points) as u128;
+    if fee_bps == 0 || amount == 0 {
+        return Ok(amount);
+    }
+    let max_fee_amount = amount
+        .checked_add(u64::from(transfer_fee.maximum_fee))
+        .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?;
+    if fee_bps >= MAX_FEE_BASIS_POINTS as u128 {
+        return Ok(max_fee_amount);
+    }
+    // The fee is rounded up, amount * 10000 / (10000 - bps) rounded up covers that
+    let one_in_bps = MAX_FEE_BASIS_POINTS as u128;
+    let uncapped_amount = (amount as u128)
+        .checked_mul(one_in_bps)
+        .and_then(|numerator| numerator.checked_add(one_in_bps - fee_bps - 1))
+        .ok_or_else(|| error!(CustomErrors::NumericalOverflow))?
+        / (one_in_bps - fee_bps);
+    Ok(std::cmp::min(uncapped_amount, max_fee_amount as u128) as u64)
+}
+
+/// Creates a vault for the mint under whichever token program owns it, with
+/// room for the extensions the mint requires its token accounts to have.
+pub fn init_vault<'info>(
+    payer: &AccountInfo<'info>,
+    vault: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let space = {
+        let data = mint.try_borrow_data()?;
+        let mint_extension_types =
+            StateWithExtensions::<Mint>::unpack(&data)?.get_extension_types()?;
+        ExtensionType::get_account_len::<TokenAccount>(
+            &ExtensionType::get_required_init_account_extensions(&mint_extension_types),
+        )
+    };
+    create_pda_account(
+        payer,
+        vault,
+        space,
+        token_program.key,
+        system_program,
+        seeds,
+    )?;
+    invoke_signed(
+        &spl_token_2022::instruction::initialize_account3(
+            token_program.key,
+            vault.key,
+            mint.key,
+            authority.key,
+        )?,
+        &[vault.clone(), mint.clone(), token_program.clone()],
+        &[],
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferChecked<'info> {
+    /// CHECK: Checked by the token program
+    pub from: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub mint: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub to: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub authority: AccountInfo<'info>,
+}
+
+/// Works for either token program, the mint's decimals come from the mint itself
+pub fn transfer_checked<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, TransferChecked<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let decimals = {
+        let data = ctx.accounts.mint.try_borrow_data()?;
+        StateWithExtensions::<Mint>::unpack(&data)?.base.decimals
+    };
+    let ix = spl_token_2022::instruction::transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.from,
+            ctx.accounts.mint,
+            ctx.accounts.to,
+            ctx.accounts.authority,
+            ctx.program,
+        ],
+        ctx.signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseAccount<'info> {
+    /// CHECK: Checked by the token program
+    pub account: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub mint: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub destination: AccountInfo<'info>,
+    /// CHECK: Checked by the token program
+    pub authority: AccountInfo<'info>,
+}
+
+/// Token-2022 accounts can't close while transfer fees are withheld in them,
+/// those get harvested to the mint first, where the fee authority collects them.
+pub fn close_account<'a, 'b, 'c, 'info>(
+    ctx: CpiContext<'a, 'b, 'c, 'info, CloseAccount<'info>>,
+) -> Result<()> {
+    if *ctx.program.key == spl_token_2022::ID && has_transfer_fee(&ctx.accounts.mint)? {
+        invoke_signed(
+            &spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint(
+                ctx.program.key,
+                ctx.accounts.mint.key,
+                &[ctx.accounts.account.key],
+            )?,
+            &[
+                ctx.accounts.mint.clone(),
+                ctx.accounts.account.clone(),
+                ctx.program.clone(),
+            ],
+            &[],
+        )?;
+    }
+    let ix = spl_token_2022::instruction::close_account(
+        ctx.program.key,
+        ctx.accounts.account.key,
+        ctx.accounts.destination.key,
+        ctx.accounts.authority.key,
+        &[],
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.account,
+            ctx.accounts.destination,
+            ctx.accounts.authority,
+            ctx.program,
+        ],
+        ctx.signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee(transfer_fee_basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: transfer_fee_basis_points.into(),
+        }
+    }
+
+    fn amount_received(transfer_fee: &TransferFee, amount_sent: u64) -> u64 {
+        amount_sent - transfer_fee.calculate(amount_sent).unwrap()
+    }
+
+    #[test]
+    fn grossed_up_amount_arrives_in_full() {
+        for fee_bps in [1, 50, 333, 5_000, 9_999].iter() {
+            let transfer_fee = fee(*fee_bps, u32::MAX as u64);
+            for amount in [1, 2, 3, 7, 100, 12_345, 1_000_000_007].iter() {
+                let amount_sent = amount_before_fee(&transfer_fee, *amount).unwrap();
+                assert_eq!(amount_received(&transfer_fee, amount_sent), *amount);
+            }
+        }
+    }
+
+    #[test]
+    fn capped_fee_only_adds_the_maximum_fee() {
+        let transfer_fee = fee(500, 10);
+        assert_eq!(
+            amount_before_fee(&transfer_fee, 1_000_000).unwrap(),
+            1_000_010
+        );
+        assert_eq!(amount_received(&transfer_fee, 1_000_010), 1_000_000);
+        let transfer_fee = fee(MAX_FEE_BASIS_POINTS, 10);
+        assert_eq!(amount_before_fee(&transfer_fee, 100).unwrap(), 110);
+    }
+
+    #[test]
+    fn no_fee_leaves_the_amount_alone() {
+        assert_eq!(amount_before_fee(&fee(0, 10), 100).unwrap(), 100);
+        assert_eq!(amount_before_fee(&fee(500, 10), 0).unwrap(), 0);
+    }
+}
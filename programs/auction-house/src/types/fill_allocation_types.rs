@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// How fills are handed out between orders sitting at the marginal price,
+/// orders at better prices are always filled in full.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum FillAllocationTypes {
+    /// Earlier orders at the marginal price are filled first
+    PriceTime,
+    /// Every order at the marginal price gets the same share of its quantity
+    ProRata,
+}
+
+impl Default for FillAllocationTypes {
+    fn default() -> Self {
+        FillAllocationTypes::PriceTime
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Rule used to pick the uniform clearing price once the marginal
+/// bid and ask have been found.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum FinalPriceTypes {
+    /// Price of the lowest bid that still gets (partially) filled
+    LowestWinningBid,
+    /// Price of the highest ask that still gets (partially) filled
+    HighestWinningAsk,
+    /// Midpoint of the final bid and final ask prices, rounded down to a tick
+    Midpoint,
+    /// Price of the highest bid that doesn't get filled, never below the final ask
+    FirstLosingBid,
+}
+
+impl Default for FinalPriceTypes {
+    fn default() -> Self {
+        FinalPriceTypes::LowestWinningBid
+    }
+}
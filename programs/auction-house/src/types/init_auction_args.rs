@@ -1,14 +1,54 @@
 use anchor_lang::prelude::*;
 
+use crate::types::*;
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitAuctionArgs {
     pub auction_id: [u8; 10],
     pub start_order_phase: i64,
     pub end_order_phase: i64,
     pub end_decryption_phase: i64,
+    // Soft close, orders within the window before the end of the order phase extend it
+    pub soft_close_window: i64,
+    pub soft_close_extension: i64,
+    pub max_soft_close_extension: i64,
     pub are_asks_encrypted: bool,
     pub are_bids_encrypted: bool,
     pub nacl_pubkey: Vec<u8>, // 32 bytes
+    // Encrypted orders are hash commitments the users reveal themselves instead,
+    // unrevealed orders forfeit part of their deposit
+    pub is_commit_reveal: bool,
+    pub unrevealed_penalty_bps: u16,
+    // Optional k of n committee, orders are encrypted to the committee's combined
+    // key and can only be decrypted once threshold members have submitted their
+    // key shares. Empty for a single auctioneer key.
+    pub committee: Vec<Pubkey>,
+    pub committee_threshold: u8,
+    // Each member's share of the combined secret key from a dealerless key
+    // generation, times the ed25519 base point, compressed. They have to
+    // interpolate to nacl_pubkey, the sum of the members' public keys.
+    pub committee_public_shares: Vec<[u8; 32]>,
+    pub final_price_type: FinalPriceTypes,
+    pub fill_allocation_type: FillAllocationTypes,
+    pub is_pay_as_bid: bool,
+    // Quantities are in lots and prices are quote lots per base lot, lot
+    // sizes in atoms let any pair of mint decimals work
+    pub base_lot_size: u64,
+    pub quote_lot_size: u64,
     pub min_base_order_size: u64,
     pub tick_size: u64,
+    pub max_base_per_user: u64, // Zero for no limit
+    pub allowlist_root: [u8; 32], // All zeroes for no allowlist
+    pub reserve_price: u64, // FP32
+    // Optional keccak hash of the reserve price and a salt, only revealed by the
+    // auctioneer during the decryption phase. All zeroes for no hidden reserve price
+    pub reserve_price_commitment: [u8; 32],
+    // All or nothing thresholds, the auction fails if either isn't reached
+    pub min_base_matched: u64,
+    pub min_quote_raised: u64,
+    // Single seller mode, the auctioneer sells this much base instead of taking ask orders
+    pub fixed_base_supply: u64,
+    // Purchased base vests linearly from the end of the decryption phase, zero duration for no vesting
+    pub vesting_cliff: i64,
+    pub vesting_duration: i64,
 }
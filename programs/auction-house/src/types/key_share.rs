@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+// Member's share of the committee's secret key, indexed into the auction's committee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct KeyShare {
+    pub member_idx: u8,
+    pub share: [u8; 32],
+}
@@ -1,9 +1,19 @@
 pub use aob_bumps::*;
 pub use encrypted_order::*;
+pub use fill_allocation_types::*;
+pub use final_price_types::*;
 pub use init_auction_args::*;
+pub use key_share::*;
+pub use order_args::*;
+pub use order_record::*;
 pub use side::*;
 
 mod aob_bumps;
 mod encrypted_order;
+mod fill_allocation_types;
+mod final_price_types;
 mod init_auction_args;
+mod key_share;
+mod order_args;
+mod order_record;
 mod side;
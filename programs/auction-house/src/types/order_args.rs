@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OrderArgs {
+    pub limit_price: u64, // FP32
+    pub max_base_qty: u64,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+// Filled in as the order's events are consumed and copied into the user's
+// order history when they settle, quantities are in lots
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct OrderRecord {
+    pub order_id: u128,
+    pub limit_price: u64,
+    pub base_qty: u64,
+    pub base_filled: u64,
+    pub quote_filled: u64,
+    pub base_refunded: u64,
+    pub quote_refunded: u64,
+}
+
+impl OrderRecord {
+    pub fn new(order_id: u128, limit_price: u64, base_qty: u64) -> Self {
+        OrderRecord {
+            order_id,
+            limit_price,
+            base_qty,
+            ..OrderRecord::default()
+        }
+    }
+}
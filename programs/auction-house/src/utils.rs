@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+
+/// Creates a rent exempt PDA owned by `owner`, for accounts Anchor can't `init`
+/// through its constraints, e.g. when it only sometimes needs to exist.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    system_program: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                account.key,
+                rent_exempt_lamports,
+                space as u64,
+                owner,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+            &[seeds],
+        )?;
+    } else {
+        // Someone sent lamports to the address first, which would make create_account fail
+        let lamports_needed = rent_exempt_lamports.saturating_sub(account.lamports());
+        if lamports_needed > 0 {
+            invoke(
+                &system_instruction::transfer(payer.key, account.key, lamports_needed),
+                &[payer.clone(), account.clone(), system_program.clone()],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(account.key, space as u64),
+            &[account.clone(), system_program.clone()],
+            &[seeds],
+        )?;
+        invoke_signed(
+            &system_instruction::assign(account.key, owner),
+            &[account.clone(), system_program.clone()],
+            &[seeds],
+        )?;
+    }
+    Ok(())
+}
+
+/// Grows an account owned by this program to `space` bytes, the extra rent is
+/// taken from `funder`, another account owned by this program. Both have to be
+/// writable.
+pub fn realloc_program_account(
+    account: &AccountInfo,
+    funder: &AccountInfo,
+    space: usize,
+) -> Result<()> {
+    if account.data_len() >= space {
+        return Ok(());
+    }
+    let lamports_needed = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    let funder_lamports = funder.lamports().checked_sub(lamports_needed).unwrap();
+    let account_lamports = account.lamports().checked_add(lamports_needed).unwrap();
+    **funder.try_borrow_mut_lamports()? = funder_lamports;
+    **account.try_borrow_mut_lamports()? = account_lamports;
+    account.realloc(space, false)?;
+    Ok(())
+}
@@ -28,7 +28,6 @@ import {
 } from "../../../generated/instructions";
 import { toFp32, toFpLimitPrice } from "../../../tests/sdk/utils";
 import { BN } from "@project-serum/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 const AdminView = () => {
   const router = useRouter();
@@ -135,7 +134,6 @@ const AdminView = () => {
         return;
 
       const auction = selected.auction;
-      const { quoteMint, baseMint } = auction;
 
       // TODO: move to auction store
       let [openOrdersPk] = await PublicKey.findProgramAddress(
@@ -156,6 +154,15 @@ const AdminView = () => {
         ],
         programId
       );
+      let [vestingPk] = await PublicKey.findProgramAddress(
+        [
+          wallet.publicKey!.toBuffer(),
+          Buffer.from("vesting"),
+          Buffer.from(auction.auctionId),
+          auction.authority.toBuffer(),
+        ],
+        programId
+      );
 
       const tx = new Transaction();
 
@@ -163,14 +170,18 @@ const AdminView = () => {
       if (!openOrders) {
         tx.add(
           initOpenOrders(
-            { side: new Side.Ask(), maxOrders: 2 },
+            {
+              side: new Side.Ask(),
+              maxOrders: 2,
+              allowlistProof: [],
+              maxBaseAllocation: new BN(0),
+            },
             {
               user: wallet.publicKey!,
               auction: new PublicKey(pk),
               openOrders: openOrdersPk,
               orderHistory: orderHistoryPk,
-              quoteMint,
-              baseMint,
+              vesting: vestingPk,
               userQuote: quoteToken,
               userBase: baseToken,
               systemProgram: SystemProgram.programId,
@@ -230,7 +241,6 @@ const AdminView = () => {
               openOrders: openOrdersPk,
               userQuote: quoteToken,
               userBase: baseToken,
-              tokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -252,7 +262,6 @@ const AdminView = () => {
               openOrders: openOrdersPk,
               userQuote: quoteToken,
               userBase: baseToken,
-              tokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
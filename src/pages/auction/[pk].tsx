@@ -33,7 +33,6 @@ import { BN } from "@project-serum/anchor";
 import {
   createAssociatedTokenAccountInstruction,
   getAssociatedTokenAddress,
-  TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import { token } from "@project-serum/anchor/dist/cjs/utils";
 
@@ -143,7 +142,7 @@ const AuctionView = () => {
         return;
 
       const auction = selected.auction;
-      const { quoteMint, baseMint } = auction;
+      const { baseMint, baseTokenProgram } = auction;
 
       const tx = new Transaction();
 
@@ -151,14 +150,17 @@ const AuctionView = () => {
       if (!baseToken) {
         baseTokenAddr = await getAssociatedTokenAddress(
           baseMint,
-          wallet.publicKey
+          wallet.publicKey,
+          false,
+          baseTokenProgram
         );
         tx.add(
           createAssociatedTokenAccountInstruction(
             wallet.publicKey,
             baseTokenAddr,
             wallet.publicKey,
-            baseMint
+            baseMint,
+            baseTokenProgram
           )
         );
       }
@@ -182,19 +184,32 @@ const AuctionView = () => {
         ],
         programId
       );
+      let [vestingPk] = await PublicKey.findProgramAddress(
+        [
+          wallet.publicKey!.toBuffer(),
+          Buffer.from("vesting"),
+          Buffer.from(auction.auctionId),
+          auction.authority.toBuffer(),
+        ],
+        programId
+      );
 
       const openOrders = await OpenOrders.fetch(connection, openOrdersPk);
       if (!openOrders) {
         tx.add(
           initOpenOrders(
-            { side: new Side.Bid(), maxOrders: 2 },
+            {
+              side: new Side.Bid(),
+              maxOrders: 2,
+              allowlistProof: [],
+              maxBaseAllocation: new BN(0),
+            },
             {
               user: wallet.publicKey,
               auction: new PublicKey(pk),
               openOrders: openOrdersPk,
               orderHistory: orderHistoryPk,
-              quoteMint,
-              baseMint,
+              vesting: vestingPk,
               userQuote: quoteToken,
               userBase: baseToken || baseTokenAddr!,
               systemProgram: SystemProgram.programId,
@@ -254,7 +269,6 @@ const AuctionView = () => {
               openOrders: openOrdersPk,
               userQuote: quoteToken,
               userBase: baseToken || baseTokenAddr!,
-              tokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -276,7 +290,6 @@ const AuctionView = () => {
               openOrders: openOrdersPk,
               userQuote: quoteToken,
               userBase: baseToken || baseTokenAddr!,
-              tokenProgram: TOKEN_PROGRAM_ID,
             }
           )
         );
@@ -319,7 +332,6 @@ const AuctionView = () => {
           openOrders: openOrdersPk,
           userQuote: quoteToken,
           userBase: baseToken,
-          tokenProgram: TOKEN_PROGRAM_ID,
         }
       )
     );
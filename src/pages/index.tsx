@@ -4,7 +4,6 @@ import {
   PublicKey,
   Signer,
   SystemProgram,
-  SYSVAR_RENT_PUBKEY,
   Transaction,
 } from "@solana/web3.js";
 import { useEffect, useState } from "react";
@@ -14,6 +13,7 @@ import * as nacl from "tweetnacl";
 import { Auction, getCreateAccountParams, toFp32 } from "../../tests/sdk";
 import { initAuction } from "../../generated/instructions";
 import { Auction as GenAuction } from "../../generated/accounts";
+import { FillAllocationTypes, FinalPriceTypes } from "../../generated/types";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import Modal from "../components/Modal";
 import useLocalStorageState from "../hooks/useLocalStorageState";
@@ -162,8 +162,8 @@ const AuctionsList = () => {
       baseMint: new PublicKey(data.baseMint),
       quoteVault,
       baseVault,
-      rent: SYSVAR_RENT_PUBKEY,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+      baseTokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       // Args
       auctionId,
@@ -172,9 +172,31 @@ const AuctionsList = () => {
       endDecryptionPhase: nowBn.add(
         new BN(data.orderPhaseLength + data.decryptionPhaseLength)
       ),
+      softCloseWindow: new BN(0),
+      softCloseExtension: new BN(0),
+      maxSoftCloseExtension: new BN(0),
+      naclPubkey: localAuctionKey.publicKey,
+      isCommitReveal: false,
+      unrevealedPenaltyBps: 0,
+      committee: [],
+      committeeThreshold: 0,
+      committeePublicShares: [],
+      finalPriceType: new FinalPriceTypes.LowestWinningBid(),
+      fillAllocationType: new FillAllocationTypes.PriceTime(),
+      isPayAsBid: false,
+      baseLotSize: new BN(1),
+      quoteLotSize: new BN(1),
       minBaseOrderSize: new BN(data.minBaseOrderSize),
       tickSize: toFp32(data.tickSize),
-      naclPubkey: localAuctionKey.publicKey,
+      maxBasePerUser: new BN(0),
+      allowlistRoot: new Array(32).fill(0),
+      reservePrice: new BN(0),
+      reservePriceCommitment: new Array(32).fill(0),
+      minBaseMatched: new BN(0),
+      minQuoteRaised: new BN(0),
+      fixedBaseSupply: new BN(0),
+      vestingCliff: new BN(0),
+      vestingDuration: new BN(0),
     };
 
     console.log("auction", auction.auctioneer.toBase58());
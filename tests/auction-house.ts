@@ -305,7 +305,7 @@ describe("auction-house", () => {
     let tx = new anchor.web3.Transaction();
     tx.add(
       genInstr.cancelEncryptedOrder(
-        { orderIdx: new BN(0) },
+        { orderIdx: 0 },
         { ...thisBidUser, ...auction }
       )
     );
@@ -361,7 +361,7 @@ describe("auction-house", () => {
     }
     let tx = new anchor.web3.Transaction();
     tx.add(
-      genInstr.calculateClearingPrice({ limit: new BN(10) }, { ...auction })
+      genInstr.calculateClearingPrice({ limit: 10 }, { ...auction })
     );
     await provider.send(tx, [], { skipPreflight: true });
     let thisAuction = await genAccs.Auction.fetch(
@@ -376,8 +376,8 @@ describe("auction-house", () => {
 
   it("matches the price", async () => {
     let tx = new anchor.web3.Transaction();
-    tx.add(genInstr.matchOrders({ limit: new BN(10) }, { ...auction }));
-    tx.add(genInstr.matchOrders({ limit: new BN(10) }, { ...auction }));
+    tx.add(genInstr.matchOrders({ limit: 10 }, { ...auction }));
+    tx.add(genInstr.matchOrders({ limit: 10 }, { ...auction }));
     await provider.send(tx, [], { skipPreflight: true });
     let thisAuction = await genAccs.Auction.fetch(
       provider.connection,
@@ -396,7 +396,7 @@ describe("auction-house", () => {
   it("consumes events", async () => {
     let tx = new anchor.web3.Transaction();
     let thisInstr = genInstr.consumeEvents(
-      { limit: new BN(10), allowNoOp: false },
+      { limit: 10, allowNoOp: false },
       { ...auction }
     );
     // This is how we add remaining accounts to the transaction instruction
@@ -409,7 +409,7 @@ describe("auction-house", () => {
     tx = new anchor.web3.Transaction();
     tx.add(
       genInstr.consumeEvents(
-        { limit: new BN(10), allowNoOp: false },
+        { limit: 10, allowNoOp: false },
         { ...auction }
       )
     );
@@ -439,6 +439,7 @@ describe("Testing out the cranks for processing the auction phases", () => {
 
     while (true) {
       let allOrdersMatched = await matchOrdersCrank(
+        program,
         provider,
         wallet,
         auctionObj,
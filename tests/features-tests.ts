@@ -0,0 +1,1495 @@
+import * as anchor from "@project-serum/anchor";
+import { BN } from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import {
+  createAssociatedTokenAccount,
+  createMint,
+  mintTo,
+} from "@solana/spl-token";
+import { AuctionHouse } from "../target/types/auction_house";
+import { PublicKey, Keypair } from "@solana/web3.js";
+import nacl from "tweetnacl";
+import { keccak_256 } from "js-sha3";
+
+import * as genInstr from "../generated/instructions";
+import * as genTypes from "../generated/types";
+import * as genAccs from "../generated/accounts";
+import * as genErrors from "../generated/errors/custom";
+import { assert } from "chai";
+import {
+  Auction,
+  initAuctionObj,
+  createAuction,
+  User,
+  initUser,
+  createOpenOrders,
+  toFp32,
+  sleep,
+  calcClearingPriceCrank,
+  matchOrdersCrank,
+  buildAllowlist,
+  generateCommitteeKeys,
+} from "./sdk";
+import {
+  consumeEventsCrank,
+  settleAndCloseOpenOrdersCrank,
+} from "./sdk/cranks";
+
+// Behaviour tests for the auction options and instructions added on top of
+// the basic sealed bid flow in auction-house.ts. Every auction uses lot sizes
+// of 1 and a tick size of 1 so quantities are in tokens and prices are whole
+// numbers of quote tokens per base token.
+
+const provider = anchor.Provider.env();
+const wallet = provider.wallet as anchor.Wallet;
+anchor.setProvider(provider);
+const program = anchor.workspace.AuctionHouse as Program<AuctionHouse>;
+
+const tickSize = toFp32(1);
+const minBaseOrderSize = new BN(1);
+const orderPhaseLength = 15;
+const decryptionPhaseLength = 5;
+
+interface PhaseOpts {
+  areAsksEncrypted?: boolean;
+  areBidsEncrypted?: boolean;
+  orderPhaseLength?: number;
+  decryptionPhaseLength?: number;
+}
+
+// auctionIds can be up to 10 characters long
+async function newAuctionObj(
+  name: string,
+  overrides?: Partial<genTypes.InitAuctionArgsFields>,
+  opts: PhaseOpts = {}
+): Promise<Auction> {
+  return await initAuctionObj(
+    program,
+    provider,
+    wallet,
+    Array.from(Buffer.from(name.padEnd(10))),
+    opts.areAsksEncrypted ?? false,
+    opts.areBidsEncrypted ?? false,
+    minBaseOrderSize,
+    tickSize,
+    opts.orderPhaseLength ?? orderPhaseLength,
+    opts.decryptionPhaseLength ?? decryptionPhaseLength,
+    overrides
+  );
+}
+
+async function newAuction(
+  name: string,
+  overrides?: Partial<genTypes.InitAuctionArgsFields>,
+  opts: PhaseOpts = {}
+): Promise<Auction> {
+  let auction = await newAuctionObj(name, overrides, opts);
+  await createAuction(program, provider, wallet, auction);
+  return auction;
+}
+
+async function newUser(
+  auction: Auction,
+  side: genTypes.SideKind,
+  numBaseTokens: number,
+  numQuoteTokens: number,
+  maxOrders: number = 2
+): Promise<User> {
+  let user = await initUser(
+    program,
+    provider,
+    wallet,
+    auction,
+    side,
+    new BN(numBaseTokens),
+    new BN(numQuoteTokens),
+    new BN(maxOrders)
+  );
+  await createOpenOrders(provider, auction, user);
+  return user;
+}
+
+async function placeOrder(
+  auction: Auction,
+  user: User,
+  price: number,
+  quantity: number
+) {
+  let tx = new anchor.web3.Transaction();
+  tx.add(
+    genInstr.newOrder(
+      { limitPrice: toFp32(price), maxBaseQty: new BN(quantity) },
+      { ...user, ...auction }
+    )
+  );
+  await provider.send(tx, [user.userKeypair], { skipPreflight: true });
+}
+
+// Skipping preflight the error code comes back in decimal, otherwise in hex
+async function assertFails(
+  promise: Promise<any>,
+  error: { code: number },
+  message: string
+) {
+  let errorStr = "";
+  try {
+    await promise;
+  } catch (e) {
+    errorStr = e.toString();
+  }
+  assert.isTrue(
+    errorStr.includes(error.code.toString()) ||
+      errorStr.includes("0x" + error.code.toString(16)),
+    message + ", got: " + (errorStr || "no error")
+  );
+}
+
+async function waitUntil(timestamp: BN) {
+  let remainingTime = timestamp.toNumber() - Date.now() / 1000;
+  if (remainingTime > 0) {
+    await sleep(remainingTime + 1);
+  }
+}
+
+async function tokenBalance(tokenAccount: PublicKey): Promise<number> {
+  let balance = await provider.connection.getTokenAccountBalance(tokenAccount);
+  return Number(balance.value.amount);
+}
+
+async function fetchAuction(auction: Auction): Promise<genAccs.Auction> {
+  return await genAccs.Auction.fetch(provider.connection, auction.auction);
+}
+
+async function auctioneerTokenAccounts(
+  auction: Auction
+): Promise<{ auctioneerQuote: PublicKey; auctioneerBase: PublicKey }> {
+  let auctioneerQuote = await createAssociatedTokenAccount(
+    provider.connection,
+    wallet.payer,
+    auction.quoteMint,
+    wallet.publicKey,
+    undefined,
+    auction.quoteTokenProgram
+  );
+  let auctioneerBase = await createAssociatedTokenAccount(
+    provider.connection,
+    wallet.payer,
+    auction.baseMint,
+    wallet.publicKey,
+    undefined,
+    auction.baseTokenProgram
+  );
+  return { auctioneerQuote, auctioneerBase };
+}
+
+// Curve operations for the encryption keys need more than the default compute
+function requestComputeUnits(): anchor.web3.TransactionInstruction {
+  return anchor.web3.ComputeBudgetProgram.requestUnits({
+    units: 1_400_000,
+    additionalFee: 0,
+  });
+}
+
+// Plaintext of an encrypted order, the limit price followed by the quantity
+function orderPlainText(price: number, quantity: number): Buffer {
+  return Buffer.concat([
+    toFp32(price).toBuffer("le", 8),
+    new BN(quantity).toBuffer("le", 8),
+  ]);
+}
+
+// Runs the cranks from the end of the decryption phase through to settling
+// and closing every open orders account
+async function finishAuction(auction: Auction) {
+  await waitUntil(auction.endDecryptionPhase);
+  assert.isTrue(
+    await calcClearingPriceCrank(provider, wallet, auction),
+    "clearing price found"
+  );
+  assert.isTrue(
+    await matchOrdersCrank(program, provider, wallet, auction),
+    "orders matched"
+  );
+  assert.isTrue(await consumeEventsCrank(provider, auction), "events consumed");
+  assert.isTrue(
+    await settleAndCloseOpenOrdersCrank(program, provider, auction),
+    "open orders settled"
+  );
+}
+
+describe("final price types", () => {
+  // Ask 100 @ 8, bids 60 @ 14, 40 @ 12 and 50 @ 11.
+  // The bids at 14 and 12 fill the ask, the bid at 11 is the first one to lose.
+  const finalPriceTypes: Array<[string, genTypes.FinalPriceTypesKind, number]> =
+    [
+      ["price-low", new genTypes.FinalPriceTypes.LowestWinningBid(), 12],
+      ["price-high", new genTypes.FinalPriceTypes.HighestWinningAsk(), 8],
+      ["price-mid", new genTypes.FinalPriceTypes.Midpoint(), 10],
+      ["price-lose", new genTypes.FinalPriceTypes.FirstLosingBid(), 11],
+    ];
+  let auctions: Array<Auction> = [];
+  let users: Array<Array<User>> = [];
+
+  it("places the same orders in an auction for each final price type", async () => {
+    auctions = await Promise.all(
+      finalPriceTypes.map(([name, finalPriceType]) =>
+        newAuction(name, { finalPriceType })
+      )
+    );
+    users = await Promise.all(
+      auctions.map((auction) =>
+        Promise.all([
+          newUser(auction, new genTypes.Side.Ask(), 100, 0),
+          newUser(auction, new genTypes.Side.Bid(), 0, 840),
+          newUser(auction, new genTypes.Side.Bid(), 0, 480),
+          newUser(auction, new genTypes.Side.Bid(), 0, 550),
+        ])
+      )
+    );
+    await Promise.all(
+      auctions.map(async (auction, idx) => {
+        let [askUser, bidUser1, bidUser2, bidUser3] = users[idx];
+        await placeOrder(auction, askUser, 8, 100);
+        await placeOrder(auction, bidUser1, 14, 60);
+        await placeOrder(auction, bidUser2, 12, 40);
+        await placeOrder(auction, bidUser3, 11, 50);
+      })
+    );
+  });
+
+  it("clears each auction at the price its final price type picks", async () => {
+    await Promise.all(auctions.map((auction) => finishAuction(auction)));
+    for (let [idx, auction] of auctions.entries()) {
+      let [name, , expectedPrice] = finalPriceTypes[idx];
+      let thisAuction = await fetchAuction(auction);
+      assert.isTrue(
+        thisAuction.clearingPrice.eq(toFp32(expectedPrice)),
+        name + " clears at " + expectedPrice
+      );
+      assert.isTrue(
+        thisAuction.totalQuantityMatched.eq(new BN(100)),
+        name + " matches the whole ask"
+      );
+      let [askUser] = users[idx];
+      assert.isTrue(
+        (await tokenBalance(askUser.userQuote)) == 100 * expectedPrice,
+        name + " pays the ask at the clearing price"
+      );
+    }
+  });
+
+  it("refunds the difference between the bid and the clearing price exactly", async () => {
+    let [, bidUser1, bidUser2, bidUser3] = users[0];
+    // 60 @ 14 locks 840 and pays 720, 40 @ 12 pays exactly what it locked
+    assert.isTrue((await tokenBalance(bidUser1.userBase)) == 60);
+    assert.isTrue((await tokenBalance(bidUser1.userQuote)) == 120);
+    assert.isTrue((await tokenBalance(bidUser2.userBase)) == 40);
+    assert.isTrue((await tokenBalance(bidUser2.userQuote)) == 0);
+    assert.isTrue((await tokenBalance(bidUser3.userBase)) == 0);
+    assert.isTrue((await tokenBalance(bidUser3.userQuote)) == 550);
+  });
+
+  it("keeps a record of every order in the order history", async () => {
+    let [askUser, bidUser1, , bidUser3] = users[0];
+    let bidHistory = await genAccs.OrderHistory.fetch(
+      provider.connection,
+      bidUser1.orderHistory
+    );
+    assert.isTrue(bidHistory.side.kind == "Bid");
+    assert.isTrue(bidHistory.clearingPrice.eq(toFp32(12)));
+    assert.isTrue(bidHistory.quoteAmountReturned.eq(new BN(120)));
+    assert.isTrue(bidHistory.orders.length == 1, "one order placed");
+    let bidRecord = bidHistory.orders[0];
+    assert.isTrue(bidRecord.limitPrice.eq(toFp32(14)));
+    assert.isTrue(bidRecord.baseQty.eq(new BN(60)));
+    assert.isTrue(bidRecord.baseFilled.eq(new BN(60)));
+    assert.isTrue(bidRecord.quoteFilled.eq(new BN(720)));
+    assert.isTrue(bidRecord.quoteRefunded.eq(new BN(120)));
+
+    let losingHistory = await genAccs.OrderHistory.fetch(
+      provider.connection,
+      bidUser3.orderHistory
+    );
+    assert.isTrue(losingHistory.orders[0].baseFilled.eq(new BN(0)));
+    assert.isTrue(losingHistory.orders[0].quoteRefunded.eq(new BN(550)));
+
+    let askHistory = await genAccs.OrderHistory.fetch(
+      provider.connection,
+      askUser.orderHistory
+    );
+    assert.isTrue(askHistory.side.kind == "Ask");
+    assert.isTrue(askHistory.orders[0].baseFilled.eq(new BN(100)));
+    assert.isTrue(askHistory.orders[0].quoteFilled.eq(new BN(1200)));
+  });
+});
+
+describe("pro rata fills", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUsers: Array<User>;
+
+  it("places two bids at the marginal price", async () => {
+    auction = await newAuction("pro-rata", {
+      fillAllocationType: new genTypes.FillAllocationTypes.ProRata(),
+    });
+    [askUser, ...bidUsers] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 600),
+      newUser(auction, new genTypes.Side.Bid(), 0, 600),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    await placeOrder(auction, bidUsers[0], 10, 60);
+    await placeOrder(auction, bidUsers[1], 10, 60);
+  });
+
+  it("splits the ask between the bids and settles them in one batch", async () => {
+    let settleBatch = () => {
+      let tx = new anchor.web3.Transaction();
+      let thisInstr = genInstr.settleOpenOrdersBatch({ ...auction });
+      for (let user of [askUser, ...bidUsers]) {
+        thisInstr.keys = thisInstr.keys.concat([
+          { pubkey: user.user, isSigner: false, isWritable: true },
+          { pubkey: user.openOrders, isSigner: false, isWritable: true },
+          { pubkey: user.orderHistory, isSigner: false, isWritable: true },
+          { pubkey: user.vesting, isSigner: false, isWritable: true },
+          { pubkey: user.userQuote, isSigner: false, isWritable: true },
+          { pubkey: user.userBase, isSigner: false, isWritable: true },
+        ]);
+      }
+      tx.add(thisInstr);
+      return provider.send(tx, [], { skipPreflight: true });
+    };
+    await waitUntil(auction.endDecryptionPhase);
+    await assertFails(
+      settleBatch(),
+      new genErrors.AuctionNotFinished(),
+      "settling before the orders are matched"
+    );
+    await calcClearingPriceCrank(provider, wallet, auction);
+    await matchOrdersCrank(program, provider, wallet, auction);
+    await consumeEventsCrank(provider, auction);
+    await settleBatch();
+
+    for (let bidUser of bidUsers) {
+      assert.isTrue(
+        (await tokenBalance(bidUser.userBase)) == 50,
+        "each bid gets half the ask"
+      );
+      assert.isTrue((await tokenBalance(bidUser.userQuote)) == 100);
+      assert.isNull(
+        await provider.connection.getAccountInfo(bidUser.openOrders),
+        "open orders closed"
+      );
+    }
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 1000);
+    assert.isTrue((await fetchAuction(auction)).numOpenOrders == 0);
+  });
+});
+
+describe("committed reserve price", () => {
+  const reservePrice = toFp32(12);
+  const salt = Array.from(nacl.randomBytes(32));
+  let auction: Auction;
+  let askUser: User;
+  let bidUsers: Array<User>;
+
+  it("places orders around the hidden reserve price", async () => {
+    let reservePriceCommitment = keccak_256.array(
+      Buffer.concat([reservePrice.toBuffer("le", 8), Buffer.from(salt)])
+    );
+    auction = await newAuction("reserve", { reservePriceCommitment });
+    [askUser, ...bidUsers] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 840),
+      newUser(auction, new genTypes.Side.Bid(), 0, 660),
+    ]);
+    await placeOrder(auction, askUser, 8, 100);
+    await placeOrder(auction, bidUsers[0], 14, 60);
+    await placeOrder(auction, bidUsers[1], 11, 60);
+  });
+
+  it("reveals the reserve price in the decryption phase", async () => {
+    await waitUntil(auction.endOrderPhase);
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.revealReservePrice(
+        { reservePrice, salt: Array.from(nacl.randomBytes(32)) },
+        { ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [], { skipPreflight: true }),
+      new genErrors.InvalidReservePriceKey(),
+      "the salt has to match the commitment"
+    );
+    tx = new anchor.web3.Transaction();
+    tx.add(genInstr.revealReservePrice({ reservePrice, salt }, { ...auction }));
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await fetchAuction(auction)).reservePrice.eq(reservePrice));
+  });
+
+  it("doesn't fill bids below the reserve price", async () => {
+    await finishAuction(auction);
+    let thisAuction = await fetchAuction(auction);
+    assert.isTrue(thisAuction.totalQuantityMatched.eq(new BN(60)));
+    assert.isTrue(thisAuction.clearingPrice.gte(reservePrice));
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 40);
+    assert.isTrue((await tokenBalance(bidUsers[1].userQuote)) == 660);
+  });
+
+  it("fails the auction and refunds everyone when the reserve price is never revealed", async () => {
+    let reservePriceCommitment = keccak_256.array(
+      Buffer.concat([reservePrice.toBuffer("le", 8), Buffer.from(salt)])
+    );
+    let unrevealed = await newAuction("reserve-hidden", {
+      reservePriceCommitment,
+    });
+    let [askUser, bidUser] = await Promise.all([
+      newUser(unrevealed, new genTypes.Side.Ask(), 100, 0),
+      newUser(unrevealed, new genTypes.Side.Bid(), 0, 840),
+    ]);
+    await placeOrder(unrevealed, askUser, 8, 100);
+    await placeOrder(unrevealed, bidUser, 14, 60);
+    await finishAuction(unrevealed);
+
+    let thisAuction = await fetchAuction(unrevealed);
+    assert.isTrue(thisAuction.hasFailed, "auction failed");
+    assert.isTrue(thisAuction.totalQuantityMatched.eq(new BN(0)));
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 100);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 840);
+  });
+});
+
+describe("minimum raise", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+
+  it("fails the auction and refunds everyone when too little is raised", async () => {
+    auction = await newAuction("min-raise", { minQuoteRaised: new BN(10_000) });
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 840),
+    ]);
+    await placeOrder(auction, askUser, 8, 100);
+    await placeOrder(auction, bidUser, 14, 60);
+    await finishAuction(auction);
+
+    let thisAuction = await fetchAuction(auction);
+    assert.isTrue(thisAuction.hasFailed, "auction failed");
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 100);
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 0);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 0);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 840);
+  });
+});
+
+describe("pay as bid and closing the auction", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUsers: Array<User>;
+  let auctioneerQuote: PublicKey;
+  let auctioneerBase: PublicKey;
+
+  it("fills bids at their own limit price", async () => {
+    auction = await newAuction("pay-as-bid", { isPayAsBid: true });
+    [askUser, ...bidUsers] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 840),
+      newUser(auction, new genTypes.Side.Bid(), 0, 480),
+    ]);
+    await placeOrder(auction, askUser, 8, 100);
+    await placeOrder(auction, bidUsers[0], 14, 60);
+    await placeOrder(auction, bidUsers[1], 12, 40);
+    await finishAuction(auction);
+
+    assert.isTrue((await tokenBalance(bidUsers[0].userBase)) == 60);
+    assert.isTrue((await tokenBalance(bidUsers[0].userQuote)) == 0);
+    assert.isTrue((await tokenBalance(bidUsers[1].userBase)) == 40);
+    assert.isTrue((await tokenBalance(bidUsers[1].userQuote)) == 0);
+    // The ask gets its own limit price, the spread stays in the vault
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 800);
+  });
+
+  it("sweeps what's left in the vaults to the auctioneer", async () => {
+    ({ auctioneerQuote, auctioneerBase } = await auctioneerTokenAccounts(
+      auction
+    ));
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.sweepDust({ ...auction, auctioneerQuote, auctioneerBase })
+    );
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await tokenBalance(auctioneerQuote)) == 520);
+    assert.isTrue((await tokenBalance(auction.quoteVault)) == 0);
+  });
+
+  it("closes the auction and the order history", async () => {
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.closeAobAccounts({ ...auction }));
+    tx.add(genInstr.closeAuction({ ...auction }));
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isNull(await provider.connection.getAccountInfo(auction.auction));
+    assert.isNull(await provider.connection.getAccountInfo(auction.quoteVault));
+    assert.isNull(await provider.connection.getAccountInfo(auction.baseVault));
+
+    let bidUser = bidUsers[0];
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.closeOrderHistory(
+        { auctionId: auction.auctionId, auctioneer: auction.auctioneer },
+        { ...bidUser }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    assert.isNull(
+      await provider.connection.getAccountInfo(bidUser.orderHistory)
+    );
+  });
+});
+
+describe("fixed supply", () => {
+  let auction: Auction;
+  let bidUsers: Array<User>;
+  let auctioneerQuote: PublicKey;
+  let auctioneerBase: PublicKey;
+
+  it("only takes bids once the supply is deposited", async () => {
+    auction = await newAuction("fixed-sup", { fixedBaseSupply: new BN(100) });
+    ({ auctioneerQuote, auctioneerBase } = await auctioneerTokenAccounts(
+      auction
+    ));
+    await mintTo(
+      provider.connection,
+      wallet.payer,
+      auction.baseMint,
+      auctioneerBase,
+      wallet.publicKey,
+      100
+    );
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.depositFixedSupply({ ...auction, auctioneerBase }));
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await fetchAuction(auction)).hasDepositedFixedSupply);
+    assert.isTrue((await tokenBalance(auction.baseVault)) == 100);
+
+    let askUser = await initUser(
+      program,
+      provider,
+      wallet,
+      auction,
+      new genTypes.Side.Ask(),
+      new BN(100),
+      new BN(0),
+      new BN(2)
+    );
+    await assertFails(
+      createOpenOrders(provider, auction, askUser),
+      new genErrors.AskOrdersNotAllowed(),
+      "the auctioneer is the only seller"
+    );
+
+    bidUsers = await Promise.all([
+      newUser(auction, new genTypes.Side.Bid(), 0, 840),
+      newUser(auction, new genTypes.Side.Bid(), 0, 720),
+    ]);
+    await placeOrder(auction, bidUsers[0], 14, 60);
+    await placeOrder(auction, bidUsers[1], 12, 60);
+  });
+
+  it("sells the supply and pays the proceeds to the auctioneer", async () => {
+    await finishAuction(auction);
+    let thisAuction = await fetchAuction(auction);
+    assert.isTrue(thisAuction.clearingPrice.eq(toFp32(12)));
+    assert.isTrue(thisAuction.baseSold.eq(new BN(100)));
+    assert.isTrue((await tokenBalance(bidUsers[0].userBase)) == 60);
+    assert.isTrue((await tokenBalance(bidUsers[1].userBase)) == 40);
+    assert.isTrue((await tokenBalance(bidUsers[1].userQuote)) == 240);
+
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.withdrawProceeds({
+        ...auction,
+        auctioneerQuote,
+        auctioneerBase,
+      })
+    );
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await tokenBalance(auctioneerQuote)) == 1200);
+    assert.isTrue((await fetchAuction(auction)).hasWithdrawnProceeds);
+  });
+});
+
+describe("max base per user", () => {
+  it("caps the total size of a user's bids", async () => {
+    let auction = await newAuction("max-user", { maxBasePerUser: new BN(50) });
+    let bidUser = await newUser(auction, new genTypes.Side.Bid(), 0, 1000);
+    await assertFails(
+      placeOrder(auction, bidUser, 10, 60),
+      new genErrors.MaxBasePerUserExceeded(),
+      "a single bid over the cap"
+    );
+    await placeOrder(auction, bidUser, 10, 50);
+    await assertFails(
+      placeOrder(auction, bidUser, 10, 10),
+      new genErrors.MaxBasePerUserExceeded(),
+      "bids adding up to more than the cap"
+    );
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.baseQtyOrdered.eq(new BN(50)));
+  });
+});
+
+describe("soft close", () => {
+  it("extends the order phase for late orders up to the max extension", async () => {
+    let auction = await newAuction("soft-close", {
+      softCloseWindow: new BN(1000),
+      softCloseExtension: new BN(5),
+      maxSoftCloseExtension: new BN(8),
+    });
+    let askUser = await newUser(auction, new genTypes.Side.Ask(), 300, 0, 3);
+    let expectedExtensions = [5, 8, 8];
+    for (let expectedExtension of expectedExtensions) {
+      await placeOrder(auction, askUser, 10, 100);
+      let thisAuction = await fetchAuction(auction);
+      assert.isTrue(
+        thisAuction.endOrderPhase.eq(
+          auction.endOrderPhase.add(new BN(expectedExtension))
+        ),
+        "order phase extended by " + expectedExtension
+      );
+      assert.isTrue(
+        thisAuction.endDecryptionPhase.eq(
+          auction.endDecryptionPhase.add(new BN(expectedExtension))
+        ),
+        "decryption phase moved with it"
+      );
+    }
+  });
+});
+
+describe("allowlist", () => {
+  it("only lets users on the allowlist in, up to their allocation", async () => {
+    let auction = await newAuctionObj(
+      "allowlist",
+      {},
+      { orderPhaseLength: 30 }
+    );
+    let [bidUser, otherBidUser, outsider] = await Promise.all(
+      [0, 1, 2].map(() =>
+        initUser(
+          program,
+          provider,
+          wallet,
+          auction,
+          new genTypes.Side.Bid(),
+          new BN(0),
+          new BN(1000),
+          new BN(2)
+        )
+      )
+    );
+    let allocations = [new BN(50), new BN(80)];
+    let allowlist = buildAllowlist([
+      { user: bidUser.user, maxBaseAllocation: allocations[0] },
+      { user: otherBidUser.user, maxBaseAllocation: allocations[1] },
+    ]);
+    auction.allowlistRoot = allowlist.root;
+    await createAuction(program, provider, wallet, auction);
+
+    outsider.allowlistProof = allowlist.proofs[0];
+    outsider.maxBaseAllocation = allocations[0];
+    await assertFails(
+      createOpenOrders(provider, auction, outsider),
+      new genErrors.InvalidAllowlistProof(),
+      "users not on the allowlist"
+    );
+    bidUser.allowlistProof = allowlist.proofs[0];
+    bidUser.maxBaseAllocation = allocations[1];
+    await assertFails(
+      createOpenOrders(provider, auction, bidUser),
+      new genErrors.InvalidAllowlistProof(),
+      "claiming a bigger allocation"
+    );
+    bidUser.maxBaseAllocation = allocations[0];
+    otherBidUser.allowlistProof = allowlist.proofs[1];
+    otherBidUser.maxBaseAllocation = allocations[1];
+    await createOpenOrders(provider, auction, bidUser);
+    await createOpenOrders(provider, auction, otherBidUser);
+
+    await assertFails(
+      placeOrder(auction, bidUser, 10, 60),
+      new genErrors.MaxBasePerUserExceeded(),
+      "bidding over the allocation"
+    );
+    await placeOrder(auction, bidUser, 10, 50);
+    await placeOrder(auction, otherBidUser, 10, 80);
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      otherBidUser.openOrders
+    );
+    assert.isTrue(openOrders.maxBaseAllocation.eq(allocations[1]));
+  });
+});
+
+describe("vesting", () => {
+  const vestingCliff = 15;
+  const vestingDuration = 20;
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+
+  it("keeps bought base tokens in the vault until they vest", async () => {
+    auction = await newAuction("vesting", {
+      vestingCliff: new BN(vestingCliff),
+      vestingDuration: new BN(vestingDuration),
+    });
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 1000),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    await placeOrder(auction, bidUser, 10, 100);
+    await finishAuction(auction);
+
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 1000);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 0);
+    let vesting = await genAccs.Vesting.fetch(
+      provider.connection,
+      bidUser.vesting
+    );
+    assert.isTrue(vesting.baseAmountVesting.eq(new BN(100)));
+    assert.isTrue(vesting.baseAmountClaimed.eq(new BN(0)));
+  });
+
+  it("doesn't release anything before the cliff", async () => {
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.claimVested({ ...auction, ...bidUser }));
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.NoVestedTokensToClaim(),
+      "claiming before the cliff"
+    );
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.closeVesting(
+        { auctionId: auction.auctionId, auctioneer: auction.auctioneer },
+        { ...bidUser }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.VestedTokensNotClaimed(),
+      "closing before everything is claimed"
+    );
+  });
+
+  it("releases everything once fully vested", async () => {
+    await waitUntil(auction.endDecryptionPhase.add(new BN(vestingDuration)));
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.claimVested({ ...auction, ...bidUser }));
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 100);
+
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.closeVesting(
+        { auctionId: auction.auctionId, auctioneer: auction.auctioneer },
+        { ...bidUser }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    assert.isNull(await provider.connection.getAccountInfo(bidUser.vesting));
+  });
+});
+
+describe("aborting an auction", () => {
+  it("stops new orders and refunds every order", async () => {
+    let auction = await newAuction("abort");
+    let [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 1200),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    await placeOrder(auction, bidUser, 12, 50);
+
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.abortAuction({ ...auction }));
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await fetchAuction(auction)).isCancelled);
+
+    tx = new anchor.web3.Transaction();
+    tx.add(genInstr.abortAuction({ ...auction }));
+    await assertFails(
+      provider.send(tx, [], { skipPreflight: true }),
+      new genErrors.AuctionCancelled(),
+      "aborting twice"
+    );
+    await assertFails(
+      placeOrder(auction, bidUser, 12, 50),
+      new genErrors.OrderPhaseNotActive(),
+      "placing an order after the abort"
+    );
+
+    // No need to wait for the order phase to end
+    assert.isTrue(
+      await matchOrdersCrank(program, provider, wallet, auction),
+      "orders taken off the book"
+    );
+    assert.isTrue(await consumeEventsCrank(provider, auction));
+    assert.isTrue(
+      await settleAndCloseOpenOrdersCrank(program, provider, auction)
+    );
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 100);
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 0);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 0);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 1200);
+  });
+});
+
+describe("updating an auction", () => {
+  it("changes the auction args until the order phase starts", async () => {
+    let auction = await newAuctionObj("update");
+    // Push the whole auction back so it hasn't started yet
+    const delay = new BN(60);
+    auction.startOrderPhase = auction.startOrderPhase.add(delay);
+    auction.endOrderPhase = auction.endOrderPhase.add(delay);
+    auction.endDecryptionPhase = auction.endDecryptionPhase.add(delay);
+    await createAuction(program, provider, wallet, auction);
+
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.updateAuction(
+        { args: { ...auction, tickSize: toFp32(2) } },
+        { ...auction }
+      )
+    );
+    await provider.send(tx, [], { skipPreflight: true });
+    assert.isTrue((await fetchAuction(auction)).tickSize.eq(toFp32(2)));
+
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.updateAuction(
+        {
+          args: {
+            ...auction,
+            auctionId: Array.from(Buffer.from("other".padEnd(10))),
+          },
+        },
+        { ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [], { skipPreflight: true }),
+      new genErrors.AuctionIdCannotChange(),
+      "the auction id is part of the auction's address"
+    );
+
+    let bidUser = await initUser(
+      program,
+      provider,
+      wallet,
+      auction,
+      new genTypes.Side.Bid(),
+      new BN(0),
+      new BN(1000),
+      new BN(2)
+    );
+    await assertFails(
+      createOpenOrders(provider, auction, bidUser),
+      new genErrors.OrderPhaseNotActive(),
+      "the order phase hasn't started"
+    );
+  });
+});
+
+describe("lot sizes", () => {
+  it("converts lots to tokens with different mint decimals", async () => {
+    let auction = await newAuctionObj("lot-sizes", {
+      baseLotSize: new BN(1000),
+      quoteLotSize: new BN(10),
+    });
+    auction.baseMint = await createMint(
+      provider.connection,
+      wallet.payer,
+      wallet.publicKey,
+      null,
+      9
+    );
+    await createAuction(program, provider, wallet, auction);
+    let [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100_000, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 2000),
+    ]);
+    // 100 base lots at 2 quote lots each
+    await placeOrder(auction, askUser, 2, 100);
+    await placeOrder(auction, bidUser, 2, 100);
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 0);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 0);
+    await finishAuction(auction);
+
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 2000);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 100_000);
+  });
+});
+
+describe("token programs", () => {
+  it("rejects mints that aren't owned by a token program", async () => {
+    let auction = await newAuctionObj("token-prog");
+    auction.baseMint = Keypair.generate().publicKey;
+    auction.baseTokenProgram = anchor.web3.SystemProgram.programId;
+    await assertFails(
+      createAuction(program, provider, wallet, auction),
+      new genErrors.InvalidTokenProgram(),
+      "a system account as the base mint"
+    );
+  });
+});
+
+describe("quote budget orders", () => {
+  it("sizes a bid from the quote tokens to spend", async () => {
+    let auction = await newAuction("quote-bud");
+    let [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 1000),
+    ]);
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newQuoteBudgetOrder(
+        { limitPrice: toFp32(10), maxQuoteQty: new BN(555) },
+        { ...bidUser, ...auction }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    // 55 base at 10, the 5 left over doesn't buy a whole lot
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.quoteTokenLocked.eq(new BN(550)));
+    assert.isTrue(openOrders.baseQtyOrdered.eq(new BN(55)));
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 450);
+
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newQuoteBudgetOrder(
+        { limitPrice: toFp32(10), maxQuoteQty: new BN(555) },
+        { ...askUser, ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [askUser.userKeypair], { skipPreflight: true }),
+      new genErrors.QuoteBudgetOnlyForBids(),
+      "asks are sized in base"
+    );
+  });
+});
+
+describe("amending orders", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+  let placedEvents: Array<any> = [];
+  let cancelledEvents: Array<any> = [];
+  let listeners: Array<number> = [];
+
+  before(() => {
+    listeners.push(
+      program.addEventListener("OrderPlaced", (event) =>
+        placedEvents.push(event)
+      )
+    );
+    listeners.push(
+      program.addEventListener("OrderCancelled", (event) =>
+        cancelledEvents.push(event)
+      )
+    );
+  });
+
+  after(async () => {
+    for (let listener of listeners) {
+      await program.removeEventListener(listener);
+    }
+  });
+
+  it("moves the difference in tokens when an order is amended", async () => {
+    auction = await newAuction("amend");
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 1000),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    await placeOrder(auction, bidUser, 10, 50);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 500);
+
+    for (let [user, price, quantity] of [
+      [askUser, 12, 60],
+      [bidUser, 12, 50],
+    ] as Array<[User, number, number]>) {
+      let openOrders = await genAccs.OpenOrders.fetch(
+        provider.connection,
+        user.openOrders
+      );
+      let orderId = openOrders.orders[0];
+      let tx = new anchor.web3.Transaction();
+      tx.add(
+        genInstr.amendOrder(
+          {
+            orderId,
+            limitPrice: toFp32(price),
+            maxBaseQty: new BN(quantity),
+          },
+          { ...user, ...auction }
+        )
+      );
+      await provider.send(tx, [user.userKeypair], { skipPreflight: true });
+      openOrders = await genAccs.OpenOrders.fetch(
+        provider.connection,
+        user.openOrders
+      );
+      assert.isTrue(openOrders.numOrders == 1, "the order is replaced");
+      assert.isFalse(openOrders.orders[0].eq(orderId), "under a new order id");
+    }
+    // The ask gets 40 base back, the bid pays 100 more for the higher price
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 40);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 400);
+  });
+
+  it("shrinks an order at the same price in place", async () => {
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      askUser.openOrders
+    );
+    let orderId = openOrders.orders[0];
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.amendOrder(
+        { orderId, limitPrice: toFp32(12), maxBaseQty: new BN(30) },
+        { ...askUser, ...auction }
+      )
+    );
+    await provider.send(tx, [askUser.userKeypair], { skipPreflight: true });
+    openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      askUser.openOrders
+    );
+    assert.isTrue(openOrders.orders[0].eq(orderId), "keeps its order id");
+    assert.isTrue(openOrders.baseQtyOrdered.eq(new BN(30)));
+    assert.isTrue((await tokenBalance(askUser.userBase)) == 70);
+  });
+
+  it("emits events for placed and cancelled orders", async () => {
+    // Give the log subscription time to catch up
+    await sleep(2, false);
+    let thisAuctionPlaced = placedEvents.filter((event) =>
+      event.auction.equals(auction.auction)
+    );
+    let thisAuctionCancelled = cancelledEvents.filter((event) =>
+      event.auction.equals(auction.auction)
+    );
+    assert.isTrue(
+      thisAuctionPlaced.length == 5,
+      "two new orders and three amends"
+    );
+    assert.isTrue(thisAuctionCancelled.length == 3, "three amends");
+    let bidPlaced = thisAuctionPlaced.filter(
+      (event) =>
+        event.user.equals(bidUser.user) && event.limitPrice.eq(toFp32(12))
+    );
+    assert.isTrue(bidPlaced.length == 1, "the amended bid");
+    assert.isTrue(bidPlaced[0].baseQty.eq(new BN(50)));
+  });
+});
+
+describe("multiple orders", () => {
+  it("places several orders at once and cancels them all", async () => {
+    let auction = await newAuction("multi-ord");
+    let bidUser = await newUser(auction, new genTypes.Side.Bid(), 0, 1000, 3);
+    let orders = [10, 9, 8].map((price) => ({
+      limitPrice: toFp32(price),
+      maxBaseQty: new BN(10),
+    }));
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.newOrders({ orders: [] }, { ...bidUser, ...auction }));
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.NoOrdersToPlace(),
+      "an empty batch"
+    );
+
+    tx = new anchor.web3.Transaction();
+    tx.add(genInstr.newOrders({ orders }, { ...bidUser, ...auction }));
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.numOrders == 3);
+    assert.isTrue(openOrders.quoteTokenLocked.eq(new BN(270)));
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 730);
+
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newOrders({ orders: [orders[0]] }, { ...bidUser, ...auction })
+    );
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.TooManyOrders(),
+      "more orders than max orders"
+    );
+
+    tx = new anchor.web3.Transaction();
+    tx.add(genInstr.cancelAllOrders({ ...bidUser, ...auction }));
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.numOrders == 0);
+    assert.isTrue(openOrders.quoteTokenLocked.eq(new BN(0)));
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 1000);
+  });
+});
+
+describe("revealing your own orders", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+
+  it("places an encrypted bid", async () => {
+    auction = await newAuction(
+      "reveal-own",
+      {},
+      { areBidsEncrypted: true, decryptionPhaseLength: 10 }
+    );
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 600),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    let nonce = nacl.randomBytes(nacl.box.nonceLength);
+    let cipherText = nacl.box(
+      orderPlainText(12, 50),
+      nonce,
+      Uint8Array.from(auction.naclPubkey),
+      bidUser.naclKeypair.secretKey
+    );
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newEncryptedOrder(
+        {
+          tokenQty: new BN(600),
+          naclPubkey: bidUser.naclPubkey,
+          nonce: Array.from(nonce),
+          cipherText: Array.from(cipherText),
+        },
+        { ...bidUser, ...auction }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+  });
+
+  it("posts the bid with the user's secret key", async () => {
+    await waitUntil(auction.endOrderPhase);
+    let tx = new anchor.web3.Transaction();
+    tx.add(requestComputeUnits());
+    tx.add(
+      genInstr.revealOwnOrders(
+        { secretKey: Array.from(nacl.randomBytes(32)) },
+        { ...bidUser, ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.EncryptionPubkeysDoNotMatch(),
+      "a key that isn't the open orders' key"
+    );
+
+    tx = new anchor.web3.Transaction();
+    tx.add(requestComputeUnits());
+    tx.add(
+      genInstr.revealOwnOrders(
+        { secretKey: Array.from(bidUser.naclKeypair.secretKey) },
+        { ...bidUser, ...auction }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.encryptedOrders.length == 0);
+    assert.isTrue(openOrders.orders.length == 1, "bid posted to the book");
+
+    await finishAuction(auction);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 50);
+  });
+});
+
+describe("commit reveal", () => {
+  const revealedOrder = orderPlainText(12, 50);
+  const revealedSalt = Array.from(nacl.randomBytes(32));
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+
+  function commitOrder(
+    plainText: Buffer,
+    salt: Array<number>,
+    tokenQty: number
+  ) {
+    return genInstr.newEncryptedOrder(
+      {
+        tokenQty: new BN(tokenQty),
+        naclPubkey: bidUser.naclPubkey,
+        nonce: Array.from(nacl.randomBytes(nacl.box.nonceLength)),
+        cipherText: keccak_256.array(
+          Buffer.concat([plainText, Buffer.from(salt)])
+        ),
+      },
+      { ...bidUser, ...auction }
+    );
+  }
+
+  it("commits to orders with a hash", async () => {
+    auction = await newAuction(
+      "commit",
+      { isCommitReveal: true, unrevealedPenaltyBps: 1000 },
+      { areBidsEncrypted: true, decryptionPhaseLength: 10 }
+    );
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 2000),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newEncryptedOrder(
+        {
+          tokenQty: new BN(600),
+          naclPubkey: bidUser.naclPubkey,
+          nonce: Array.from(nacl.randomBytes(nacl.box.nonceLength)),
+          cipherText: Array.from(nacl.randomBytes(33)),
+        },
+        { ...bidUser, ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.InvalidCommitment(),
+      "commitments are 32 byte hashes"
+    );
+
+    tx = new anchor.web3.Transaction();
+    tx.add(commitOrder(revealedOrder, revealedSalt, 600));
+    // Never revealed
+    tx.add(
+      commitOrder(orderPlainText(10, 40), Array.from(nacl.randomBytes(32)), 400)
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 1000);
+  });
+
+  it("reveals an order with its salt", async () => {
+    await waitUntil(auction.endOrderPhase);
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.revealOrder(
+        {
+          orderIdx: 0,
+          priceAndQuantity: Array.from(revealedOrder),
+          salt: Array.from(nacl.randomBytes(32)),
+        },
+        { ...bidUser, ...auction }
+      )
+    );
+    await assertFails(
+      provider.send(tx, [bidUser.userKeypair], { skipPreflight: true }),
+      new genErrors.InvalidCommitment(),
+      "the wrong salt"
+    );
+
+    tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.revealOrder(
+        {
+          orderIdx: 0,
+          priceAndQuantity: Array.from(revealedOrder),
+          salt: revealedSalt,
+        },
+        { ...bidUser, ...auction }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.orders.length == 1, "bid posted to the book");
+    assert.isTrue(openOrders.encryptedOrders.length == 1);
+  });
+
+  it("keeps a penalty from orders that were never revealed", async () => {
+    await waitUntil(auction.endDecryptionPhase);
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.cancelEncryptedOrder({ orderIdx: 0 }, { ...bidUser, ...auction })
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+    // 10% of the 400 locked for the unrevealed order
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 1360);
+
+    await finishAuction(auction);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 50);
+    assert.isTrue((await tokenBalance(bidUser.userQuote)) == 1360);
+  });
+});
+
+describe("key share committee", () => {
+  let auction: Auction;
+  let askUser: User;
+  let bidUser: User;
+  let members = [0, 1, 2].map(() => Keypair.generate());
+  let committeeKeys = generateCommitteeKeys(3, 2);
+
+  function submitKeyShare(member: Keypair, share: Array<number>) {
+    let tx = new anchor.web3.Transaction();
+    tx.add(requestComputeUnits());
+    tx.add(
+      genInstr.submitKeyShare(
+        { share },
+        { member: member.publicKey, auction: auction.auction }
+      )
+    );
+    return provider.send(tx, [member], { skipPreflight: true });
+  }
+
+  function decryptOrder() {
+    let tx = new anchor.web3.Transaction();
+    tx.add(requestComputeUnits());
+    // The shared key is worked out on chain from the committee's key
+    tx.add(
+      genInstr.decryptOrder({ sharedKey: [] }, { ...bidUser, ...auction })
+    );
+    return provider.send(tx, [], { skipPreflight: true });
+  }
+
+  it("refuses public shares that aren't for the committee's key", async () => {
+    let otherKeys = generateCommitteeKeys(3, 2);
+    let auctionObj = await newAuctionObj(
+      "committee",
+      {
+        committee: members.map((member) => member.publicKey),
+        committeeThreshold: 2,
+        committeePublicShares: otherKeys.publicShares,
+      },
+      { areBidsEncrypted: true, decryptionPhaseLength: 15 }
+    );
+    await assertFails(
+      createAuction(program, provider, wallet, {
+        ...auctionObj,
+        naclPubkey: committeeKeys.naclPubkey,
+        naclKeypair: undefined,
+      }),
+      new genErrors.InvalidCommitteeParams(),
+      "public shares from another key generation"
+    );
+  });
+
+  it("places a bid encrypted to the committee's key", async () => {
+    let auctionObj = await newAuctionObj(
+      "committee",
+      {
+        committee: members.map((member) => member.publicKey),
+        committeeThreshold: 2,
+        committeePublicShares: committeeKeys.publicShares,
+      },
+      { areBidsEncrypted: true, decryptionPhaseLength: 15 }
+    );
+    // Nobody holds the whole key
+    auction = {
+      ...auctionObj,
+      naclPubkey: committeeKeys.naclPubkey,
+      naclKeypair: undefined,
+    };
+    await createAuction(program, provider, wallet, auction);
+    [askUser, bidUser] = await Promise.all([
+      newUser(auction, new genTypes.Side.Ask(), 100, 0),
+      newUser(auction, new genTypes.Side.Bid(), 0, 600),
+    ]);
+    await placeOrder(auction, askUser, 10, 100);
+    let nonce = nacl.randomBytes(nacl.box.nonceLength);
+    let cipherText = nacl.box(
+      orderPlainText(12, 50),
+      nonce,
+      Uint8Array.from(committeeKeys.naclPubkey),
+      bidUser.naclKeypair.secretKey
+    );
+    let tx = new anchor.web3.Transaction();
+    tx.add(
+      genInstr.newEncryptedOrder(
+        {
+          tokenQty: new BN(600),
+          naclPubkey: bidUser.naclPubkey,
+          nonce: Array.from(nonce),
+          cipherText: Array.from(cipherText),
+        },
+        { ...bidUser, ...auction }
+      )
+    );
+    await provider.send(tx, [bidUser.userKeypair], { skipPreflight: true });
+  });
+
+  it("only takes valid shares from committee members", async () => {
+    await waitUntil(auction.endOrderPhase);
+    await assertFails(
+      decryptOrder(),
+      new genErrors.NotEnoughKeyShares(),
+      "decrypting before the threshold is reached"
+    );
+    await assertFails(
+      submitKeyShare(Keypair.generate(), committeeKeys.shares[0]),
+      new genErrors.NotACommitteeMember(),
+      "a share from outside the committee"
+    );
+    await assertFails(
+      submitKeyShare(members[0], committeeKeys.shares[1]),
+      new genErrors.InvalidKeyShare(),
+      "another member's share"
+    );
+    await submitKeyShare(members[0], committeeKeys.shares[0]);
+    await assertFails(
+      submitKeyShare(members[0], committeeKeys.shares[0]),
+      new genErrors.KeyShareAlreadySubmitted(),
+      "the same share twice"
+    );
+    let thisAuction = await fetchAuction(auction);
+    assert.isTrue(thisAuction.keyShares.length == 1);
+    assert.isTrue(thisAuction.committeeSecretKey.every((byte) => byte == 0));
+  });
+
+  it("rebuilds the key at the threshold and decrypts the bid", async () => {
+    await submitKeyShare(members[2], committeeKeys.shares[2]);
+    let thisAuction = await fetchAuction(auction);
+    assert.isTrue(
+      thisAuction.committeeSecretKey.some((byte) => byte != 0),
+      "committee key rebuilt"
+    );
+
+    await decryptOrder();
+    let openOrders = await genAccs.OpenOrders.fetch(
+      provider.connection,
+      bidUser.openOrders
+    );
+    assert.isTrue(openOrders.encryptedOrders.length == 0);
+    assert.isTrue(openOrders.orders.length == 1, "bid posted to the book");
+
+    await finishAuction(auction);
+    assert.isTrue((await tokenBalance(bidUser.userBase)) == 50);
+    assert.isTrue((await tokenBalance(askUser.userQuote)) == 600);
+  });
+});
@@ -0,0 +1,47 @@
+import { BN } from "@project-serum/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "js-sha3";
+
+// Merkle tree of the users allowed into an auction, matches is_on_allowlist in access_controls.rs.
+// Leaves are keccak(user || max base allocation as a LE u64), pairs are sorted before hashing.
+
+export interface Allowlist {
+    root: Array<number>,
+    // One proof per user, in the same order as the users passed in
+    proofs: Array<Array<Array<number>>>,
+  }
+
+export function allowlistLeaf(user: PublicKey, maxBaseAllocation: BN): Array<number> {
+    return keccak_256.array(Buffer.concat([user.toBuffer(), maxBaseAllocation.toArrayLike(Buffer, "le", 8)]))
+  }
+
+export function buildAllowlist(users: Array<{user: PublicKey, maxBaseAllocation: BN}>): Allowlist {
+    let level = users.map((thisUser) => allowlistLeaf(thisUser.user, thisUser.maxBaseAllocation));
+    // Index of each user's node in the current level
+    let positions = users.map((_, idx) => idx);
+    let proofs: Array<Array<Array<number>>> = users.map(() => []);
+    while (level.length > 1) {
+      let nextLevel: Array<Array<number>> = [];
+      for (let idx = 0; idx < level.length; idx += 2) {
+        // An odd node out is carried up to the next level as is
+        nextLevel.push(idx + 1 < level.length ? hashPair(level[idx], level[idx + 1]) : level[idx]);
+      }
+      positions = positions.map((position, userIdx) => {
+        let sibling = position ^ 1;
+        if (sibling < level.length) {
+          proofs[userIdx].push(level[sibling]);
+        }
+        return position >> 1
+      });
+      level = nextLevel;
+    }
+    return {
+      root: level[0],
+      proofs,
+    }
+  }
+
+function hashPair(a: Array<number>, b: Array<number>): Array<number> {
+    let [first, second] = Buffer.compare(Buffer.from(a), Buffer.from(b)) <= 0 ? [a, b] : [b, a];
+    return keccak_256.array(Buffer.concat([Buffer.from(first), Buffer.from(second)]))
+  }
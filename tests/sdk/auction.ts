@@ -5,8 +5,11 @@ import { createAssociatedTokenAccount, createMint, createMintToCheckedInstructio
 import nacl from "tweetnacl";
 import { AuctionHouse } from "../../target/types/auction_house";
 import * as genAccs from "../../generated/accounts";
+import * as genTypes from "../../generated/types";
+import * as genInstr from "../../generated/instructions";
+import { getCreateAccountParams } from "./utils";
 
-export interface Auction {
+export interface Auction extends genTypes.InitAuctionArgsFields {
     // Accounts
     auctioneer: PublicKey,
     auction: PublicKey,
@@ -20,23 +23,14 @@ export interface Auction {
     baseMint: PublicKey,
     quoteVault: PublicKey,
     baseVault: PublicKey,
-    rent: PublicKey,
-    tokenProgram: PublicKey,
+    quoteTokenProgram: PublicKey,
+    baseTokenProgram: PublicKey,
     systemProgram: PublicKey,
-    // Args
-    auctionId: Array<number>,
-    startOrderPhase: BN,
-    endOrderPhase: BN,
-    endDecryptionPhase: BN,
-    areAsksEncrypted: boolean,
-    areBidsEncrypted: boolean,
-    minBaseOrderSize: BN,
-    tickSize: BN, // FP32
-    naclPubkey: Array<number>,
+    // Args are the same as InitAuctionArgs, tickSize is FP32
     naclKeypair?: nacl.BoxKeyPair,
   }
 
-export async function initAuctionObj(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auctionId: Array<number>, areAsksEncrypted: boolean, areBidsEncrypted: boolean, minBaseOrderSize: BN, tickSize: BN, orderPhaseLength: number, decryptionPhaseLength: number): Promise<Auction> {
+export async function initAuctionObj(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auctionId: Array<number>, areAsksEncrypted: boolean, areBidsEncrypted: boolean, minBaseOrderSize: BN, tickSize: BN, orderPhaseLength: number, decryptionPhaseLength: number, overrides?: Partial<genTypes.InitAuctionArgsFields>): Promise<Auction> {
     let baseMint = await createMint(provider.connection,
         wallet.payer,
         wallet.publicKey,
@@ -88,20 +82,43 @@ export async function initAuctionObj(program: anchor.Program<AuctionHouse>, prov
       baseMint,
       quoteVault,
       baseVault,
-      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-      tokenProgram: TOKEN_PROGRAM_ID,
+      quoteTokenProgram: TOKEN_PROGRAM_ID,
+      baseTokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: anchor.web3.SystemProgram.programId,
       // Args
       auctionId,
       startOrderPhase: nowBn,
       endOrderPhase: nowBn.add(new anchor.BN(orderPhaseLength)),
       endDecryptionPhase: nowBn.add(new anchor.BN(orderPhaseLength + decryptionPhaseLength)),
+      softCloseWindow: new BN(0),
+      softCloseExtension: new BN(0),
+      maxSoftCloseExtension: new BN(0),
       areAsksEncrypted,
       areBidsEncrypted,
-      minBaseOrderSize,
-      tickSize,
       naclKeypair,
       naclPubkey,
+      isCommitReveal: false,
+      unrevealedPenaltyBps: 0,
+      committee: [],
+      committeeThreshold: 0,
+      committeePublicShares: [],
+      finalPriceType: new genTypes.FinalPriceTypes.LowestWinningBid(),
+      fillAllocationType: new genTypes.FillAllocationTypes.PriceTime(),
+      isPayAsBid: false,
+      baseLotSize: new BN(1),
+      quoteLotSize: new BN(1),
+      minBaseOrderSize,
+      tickSize,
+      maxBasePerUser: new BN(0),
+      allowlistRoot: new Array(32).fill(0),
+      reservePrice: new BN(0),
+      reservePriceCommitment: new Array(32).fill(0),
+      minBaseMatched: new BN(0),
+      minQuoteRaised: new BN(0),
+      fixedBaseSupply: new BN(0),
+      vestingCliff: new BN(0),
+      vestingDuration: new BN(0),
+      ...overrides,
     }
   }
 
@@ -115,9 +132,22 @@ export async function fetchAuctionObj(program: anchor.Program<AuctionHouse>, pro
     ...fetchedAuction,
     auctioneer: authority,
     auction,
-    rent: anchor.web3.SYSVAR_RENT_PUBKEY,
-    tokenProgram: TOKEN_PROGRAM_ID,
     systemProgram: anchor.web3.SystemProgram.programId,
     naclKeypair,
   }
-}
\ No newline at end of file
+}
+
+// Creates the AOB accounts and inits the auction in one transaction
+export async function createAuction(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auctionObj: Auction, eventQueueBytes: number = 1000000, bidsBytes: number = 64000, asksBytes: number = 64000): Promise<void> {
+    let tx = new anchor.web3.Transaction();
+    for (let [pubkey, space] of [[auctionObj.eventQueue, eventQueueBytes], [auctionObj.bids, bidsBytes], [auctionObj.asks, asksBytes]] as Array<[PublicKey, number]>) {
+      let params = await getCreateAccountParams(program, provider, wallet, pubkey, space);
+      tx.add(anchor.web3.SystemProgram.createAccount(params));
+    }
+    if (auctionObj.committee.length > 0) {
+      // Checking the committee's public shares takes curve operations
+      tx.add(anchor.web3.ComputeBudgetProgram.requestUnits({ units: 1_400_000, additionalFee: 0 }));
+    }
+    tx.add(genInstr.initAuction({ args: { ...auctionObj } }, { ...auctionObj }));
+    await provider.send(tx, [auctionObj.eventQueueKeypair, auctionObj.bidsKeypair, auctionObj.asksKeypair], {skipPreflight: true});
+  }
@@ -0,0 +1,149 @@
+import nacl from "tweetnacl";
+
+// Dealerless key generation for a committee auction's nacl pubkey, mirrors
+// programs/auction-house/src/encryption.rs. Every member deals a random polynomial
+// to the others and publishes [coefficient]B for each of its coefficients, so the
+// shares it deals can be checked. A member's share is the sum of the shares it
+// was dealt and the committee's key is the sum of the members' keys, nobody ever
+// holds the combined secret key.
+// Shares are scalars mod the ed25519 group order and public shares are the
+// compressed ed25519 points [share]B, so a bit of curve math is needed here.
+
+const P = (BigInt(1) << BigInt(255)) - BigInt(19);
+const L = (BigInt(1) << BigInt(252)) + BigInt("27742317777372353535851937790883648493");
+const D = mod(BigInt(-121665) * invert(BigInt(121666)));
+const BASE_X = BigInt("15112221349535400772501151409588531511454012693041857206046113283949847762202");
+const BASE_Y = BigInt("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+const IDENTITY: Point = [BigInt(0), BigInt(1), BigInt(1), BigInt(0)];
+
+// Extended coordinates, x = X/Z, y = Y/Z, x*y = T/Z
+type Point = [bigint, bigint, bigint, bigint];
+
+export interface CommitteeKeys {
+    naclPubkey: Array<number>,
+    // Member i's share is f(i + 1) where f is the sum of the members' polynomials
+    shares: Array<Array<number>>,
+    publicShares: Array<Array<number>>,
+  }
+
+export function generateCommitteeKeys(committeeSize: number, threshold: number): CommitteeKeys {
+    let polynomials: Array<Array<bigint>> = [];
+    let commitments: Array<Array<Point>> = [];
+    for (let i = 0; i < committeeSize; i++) {
+      let coefficients: Array<bigint> = [];
+      for (let l = 0; l < threshold; l++) {
+        coefficients.push(fromLeBytes(nacl.randomBytes(64)) % L);
+      }
+      polynomials.push(coefficients);
+      commitments.push(coefficients.map(scalarMultBase));
+    }
+    let shares: Array<Array<number>> = [];
+    let publicShares: Array<Array<number>> = [];
+    for (let j = 0; j < committeeSize; j++) {
+      let x = BigInt(j + 1);
+      let share = BigInt(0);
+      let publicShare = IDENTITY;
+      for (let i = 0; i < committeeSize; i++) {
+        let dealtShare = evaluate(polynomials[i], x);
+        let expected = commitments[i].reduceRight((acc, commitment) => add(scalarMult(acc, x), commitment), IDENTITY);
+        if (compress(scalarMultBase(dealtShare)).some((byte, idx) => byte != compress(expected)[idx])) {
+          throw new Error(`member ${i} dealt member ${j} a bad share`);
+        }
+        share = (share + dealtShare) % L;
+        publicShare = add(publicShare, expected);
+      }
+      shares.push(toLeBytes(share));
+      publicShares.push(compress(publicShare));
+    }
+    let committeeKey = commitments.reduce((acc, memberCommitments) => add(acc, memberCommitments[0]), IDENTITY);
+    return {
+      naclPubkey: toMontgomery(committeeKey),
+      shares,
+      publicShares,
+    }
+  }
+
+function evaluate(coefficients: Array<bigint>, x: bigint): bigint {
+    return coefficients.reduceRight((acc, coefficient) => (acc * x + coefficient) % L, BigInt(0))
+  }
+
+function mod(a: bigint): bigint {
+    let result = a % P;
+    return result >= BigInt(0) ? result : result + P;
+  }
+
+function invert(a: bigint): bigint {
+    let result = BigInt(1);
+    let base = mod(a);
+    let exponent = P - BigInt(2);
+    while (exponent > BigInt(0)) {
+      if (exponent & BigInt(1)) {
+        result = mod(result * base);
+      }
+      base = mod(base * base);
+      exponent >>= BigInt(1);
+    }
+    return result
+  }
+
+// Unified addition for a = -1 twisted Edwards curves, also used for doubling
+function add(p: Point, q: Point): Point {
+    let [x1, y1, z1, t1] = p;
+    let [x2, y2, z2, t2] = q;
+    let a = mod((y1 - x1) * (y2 - x2));
+    let b = mod((y1 + x1) * (y2 + x2));
+    let c = mod(t1 * BigInt(2) * D * t2);
+    let d = mod(z1 * BigInt(2) * z2);
+    let e = b - a;
+    let f = d - c;
+    let g = d + c;
+    let h = b + a;
+    return [mod(e * f), mod(g * h), mod(f * g), mod(e * h)]
+  }
+
+function scalarMultBase(scalar: bigint): Point {
+    return scalarMult([BASE_X, BASE_Y, BigInt(1), mod(BASE_X * BASE_Y)], scalar)
+  }
+
+function scalarMult(base: Point, scalar: bigint): Point {
+    let result = IDENTITY;
+    while (scalar > BigInt(0)) {
+      if (scalar & BigInt(1)) {
+        result = add(result, base);
+      }
+      base = add(base, base);
+      scalar >>= BigInt(1);
+    }
+    return result
+  }
+
+function compress(point: Point): Array<number> {
+    let zInv = invert(point[2]);
+    let x = mod(point[0] * zInv);
+    let y = mod(point[1] * zInv);
+    let bytes = toLeBytes(y);
+    bytes[31] |= Number(x & BigInt(1)) << 7;
+    return bytes
+  }
+
+// The X25519 public key of a point, u = (1 + y) / (1 - y)
+function toMontgomery(point: Point): Array<number> {
+    return toLeBytes(mod((point[2] + point[1]) * invert(point[2] - point[1])))
+  }
+
+function fromLeBytes(bytes: Uint8Array): bigint {
+    let result = BigInt(0);
+    for (let i = bytes.length - 1; i >= 0; i--) {
+      result = (result << BigInt(8)) + BigInt(bytes[i]);
+    }
+    return result
+  }
+
+function toLeBytes(num: bigint): Array<number> {
+    let bytes: Array<number> = [];
+    for (let i = 0; i < 32; i++) {
+      bytes.push(Number(num & BigInt(255)));
+      num >>= BigInt(8);
+    }
+    return bytes
+  }
@@ -20,7 +20,7 @@ export async function calcClearingPriceCrank(provider: anchor.Provider, wallet:
         try {
             let tx = new anchor.web3.Transaction;
             tx.add(genInstr.calculateClearingPrice(
-                {limit: new BN(limit)},
+                {limit},
                 {...auctionObj}
             ));
             await provider.send(tx, [], {skipPreflight: true});
@@ -44,16 +44,30 @@ export async function calcClearingPriceCrank(provider: anchor.Provider, wallet:
 // Crank to match all the orders.
 // Returns true if all orders were matched.
 // Returns false if too many errors occured.
-export async function matchOrdersCrank(provider: anchor.Provider, wallet: anchor.Wallet, auctionObj: Auction, limit: number = 1): Promise<boolean> {
+// Auctions with a max base per user or an allowlist cap each bid's fill, which needs the
+// bidder's open orders account passed in as a remaining account.
+export async function matchOrdersCrank(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auctionObj: Auction, limit: number = 1): Promise<boolean> {
+    let bidOpenOrders = await fetchBidOpenOrdersInMatchOrder(program, provider, auctionObj);
     let numErrors = 0;
     let numOrdersMatched = 0;
     while (true) {
         try {
             let tx = new anchor.web3.Transaction;
-            tx.add(genInstr.matchOrders(
-                {limit: new BN(limit)},
+            let thisInstr = genInstr.matchOrders(
+                {limit},
                 {...auctionObj}
-            ));
+            );
+            // Bids are matched before asks, so the next `limit` bids are the ones being filled
+            let pubkeyStrs = new Set<String>(bidOpenOrders
+                .slice(numOrdersMatched, numOrdersMatched + limit)
+                .map((openOrders) => openOrders.toString())
+            );
+            for (let thisPubkeyStr of pubkeyStrs) {
+                thisInstr.keys.push({
+                    pubkey: new PublicKey(thisPubkeyStr), isSigner: false, isWritable: true
+                });
+            }
+            tx.add(thisInstr);
             await provider.send(tx, [], {skipPreflight: true});
             numErrors = 0;
             numOrdersMatched += limit;
@@ -77,6 +91,30 @@ export async function matchOrdersCrank(provider: anchor.Provider, wallet: anchor
     }
 }
 
+// The open orders account of every bid left on the book, in the order match orders fills them.
+// Empty for auctions without per user limits since the fills aren't capped.
+async function fetchBidOpenOrdersInMatchOrder(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, auctionObj: Auction): Promise<Array<PublicKey>> {
+    let thisAuction = await genAccs.Auction.fetch(provider.connection, auctionObj.auction);
+    let hasUserLimits = thisAuction.maxBasePerUser.gt(new BN(0)) || thisAuction.allowlistRoot.some((byte) => byte != 0);
+    if (!hasUserLimits) {
+        return []
+    }
+    let bids: Array<{orderId: BN, openOrders: PublicKey}> = [];
+    let fetchedUsers = await fetchUsers(program, provider, auctionObj, {});
+    for (let user of fetchedUsers) {
+        if (user.side.kind != "Bid") {
+            continue
+        }
+        let thisOpenOrders = await genAccs.OpenOrders.fetch(provider.connection, user.openOrders);
+        for (let orderId of thisOpenOrders.orders) {
+            bids.push({orderId, openOrders: user.openOrders});
+        }
+    }
+    // The best bid has the largest order id, the price is in the upper 64 bits
+    bids.sort((a, b) => b.orderId.cmp(a.orderId));
+    return bids.map((bid) => bid.openOrders)
+}
+
 // Crank to decrypt orders on all the open orders accounts.
 // Returns true if all encrypted open orders account were successfully decrypted.
 // Returns false if any errors occured but will keep running the crank until all accounts are processed.
@@ -148,7 +186,7 @@ export async function consumeEventsCrank(provider: anchor.Provider, auctionObj:
 
     let numEventsConsumed = 0
     while (true) {
-        let thisEventQueue = await EventQueue.load(provider.connection, thisAuction.eventQueue, 32);
+        let thisEventQueue = await EventQueue.load(provider.connection, thisAuction.eventQueue, 40);
         if (thisEventQueue.header.count.toNumber() == 0) {
             console.log(numEventsConsumed, "events consumed");
             return true 
@@ -163,9 +201,9 @@ export async function consumeEventsCrank(provider: anchor.Provider, auctionObj:
                 let event = thisEventQueue.parseEvent(idx);
                 let thisPubkey: PublicKey;
                 if (event instanceof EventOut) {
-                    thisPubkey = new PublicKey(event.callBackInfo);
+                    thisPubkey = new PublicKey(event.callBackInfo.slice(0, 32));
                 } else if (event instanceof EventFill) {
-                    thisPubkey = new PublicKey(event.makerCallbackInfo);
+                    thisPubkey = new PublicKey(event.makerCallbackInfo.slice(0, 32));
                 }
                 pubkeyStrs.add(thisPubkey.toString());
                 idx++
@@ -176,7 +214,7 @@ export async function consumeEventsCrank(provider: anchor.Provider, auctionObj:
         }
         let tx = new anchor.web3.Transaction;
         let thisInstr = genInstr.consumeEvents(
-            {limit: new BN(numEventsToConsume), allowNoOp: false},
+            {limit: numEventsToConsume, allowNoOp: false},
             {...auctionObj}
         );
         let remainingAccounts: anchor.web3.AccountMeta[] = [];
@@ -1,4 +1,6 @@
-export {Auction, initAuctionObj, fetchAuctionObj} from "./auction"
-export {User, initUser, fetchUsers} from "./user"
+export {Auction, initAuctionObj, fetchAuctionObj, createAuction} from "./auction"
+export {User, initUser, fetchUsers, createOpenOrders} from "./user"
 export {toFp32, toFpLimitPrice, getCreateAccountParams, sleep} from "./utils"
-export {calcClearingPriceCrank, matchOrdersCrank} from "./cranks"
\ No newline at end of file
+export {calcClearingPriceCrank, matchOrdersCrank} from "./cranks"
+export {Allowlist, allowlistLeaf, buildAllowlist} from "./allowlist"
+export {CommitteeKeys, generateCommitteeKeys} from "./committee"
@@ -6,6 +6,7 @@ import nacl from "tweetnacl";
 import { AuctionHouse } from "../../target/types/auction_house";
 import * as genTypes from "../../generated/types";
 import * as genAccs from "../../generated/accounts";
+import * as genInstr from "../../generated/instructions";
 import { Auction } from "./auction";
 import { SSL_OP_NETSCAPE_CA_DN_BUG } from "constants";
 
@@ -15,15 +16,18 @@ export interface User {
     user: PublicKey,
     openOrders: PublicKey,
     orderHistory: PublicKey,
+    vesting: PublicKey,
     userBase: PublicKey,
     userQuote: PublicKey,
     naclPubkey: Array<number>,
     naclKeypair?: nacl.BoxKeyPair,
     side: genTypes.SideKind,
     maxOrders: number,
+    allowlistProof: Array<Array<number>>,
+    maxBaseAllocation: BN,
   }
 
-export async function initUser(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auction: Auction, side: genTypes.SideKind, numBaseTokens: BN, numQuoteTokens: BN, maxOrders: BN): Promise<User>  {
+export async function initUser(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, wallet: anchor.Wallet, auction: Auction, side: genTypes.SideKind, numBaseTokens: BN, numQuoteTokens: BN, maxOrders: BN, allowlist?: {allowlistProof: Array<Array<number>>, maxBaseAllocation: BN}): Promise<User>  {
     let userKeypair = new anchor.web3.Keypair();
     let user = userKeypair.publicKey;
     await provider.connection.requestAirdrop(user, 1_000_000_00)
@@ -31,13 +35,17 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
       provider.connection,
       wallet.payer,
       auction.baseMint,
-      user
+      user,
+      undefined,
+      auction.baseTokenProgram
     );
     let userQuote = await createAssociatedTokenAccount(
       provider.connection,
       wallet.payer,
       auction.quoteMint,
-      user
+      user,
+      undefined,
+      auction.quoteTokenProgram
     );
     if (numBaseTokens.gt(new BN(0))){
       await mintTo(
@@ -47,6 +55,9 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
         userBase,
         wallet.publicKey,
         numBaseTokens.toNumber(),
+        [],
+        undefined,
+        auction.baseTokenProgram
       );
     }
     if (numQuoteTokens.gt(new BN(0))){
@@ -57,6 +68,9 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
         userQuote,
         wallet.publicKey,
         numQuoteTokens.toNumber(),
+        [],
+        undefined,
+        auction.quoteTokenProgram
       );
     }
     let [openOrders] = await anchor.web3.PublicKey.findProgramAddress(
@@ -67,6 +81,10 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
       [user.toBuffer(), Buffer.from("order_history"), Buffer.from(auction.auctionId), wallet.publicKey.toBuffer()],
       program.programId
     );
+    let [vesting] = await anchor.web3.PublicKey.findProgramAddress(
+      [user.toBuffer(), Buffer.from("vesting"), Buffer.from(auction.auctionId), wallet.publicKey.toBuffer()],
+      program.programId
+    );
     let naclKeypair = nacl.box.keyPair();
     let naclPubkey = Array.from(naclKeypair.publicKey);
     return {
@@ -74,12 +92,15 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
       user,
       openOrders,
       orderHistory,
+      vesting,
       userBase,
       userQuote,
       naclKeypair,
       naclPubkey,
       side,
-      maxOrders //: maxOrders.toNumber(), // Some weirdness going on with maxOrder types in the generated client
+      maxOrders, //: maxOrders.toNumber(), // Some weirdness going on with maxOrder types in the generated client
+      allowlistProof: allowlist ? allowlist.allowlistProof : [],
+      maxBaseAllocation: allowlist ? allowlist.maxBaseAllocation : new BN(0),
     }
   }
   export async function fetchUsers(program: anchor.Program<AuctionHouse>, provider: anchor.Provider, auction: Auction, opts?: {onlyEncrypted?: boolean, onlyEmpty?: boolean}): Promise<Array<User>>  {
@@ -110,15 +131,29 @@ export async function initUser(program: anchor.Program<AuctionHouse>, provider:
         [thisOpenOrders.authority.toBuffer(), Buffer.from("order_history"), Buffer.from(auction.auctionId), auction.auctioneer.toBuffer()],
         program.programId
       );
+      let [vesting] = await anchor.web3.PublicKey.findProgramAddress(
+        [thisOpenOrders.authority.toBuffer(), Buffer.from("vesting"), Buffer.from(auction.auctionId), auction.auctioneer.toBuffer()],
+        program.programId
+      );
       let thisUser: User = {
         ...thisOpenOrders,
         openOrders: thisOpenOrders.thisOpenOrders,
         user: thisOpenOrders.authority,
         orderHistory,
-        userBase: await getAssociatedTokenAddress(auction.baseMint, thisOpenOrders.authority),
-        userQuote: await getAssociatedTokenAddress(auction.quoteMint, thisOpenOrders.authority),
+        vesting,
+        userBase: await getAssociatedTokenAddress(auction.baseMint, thisOpenOrders.authority, false, auction.baseTokenProgram),
+        userQuote: await getAssociatedTokenAddress(auction.quoteMint, thisOpenOrders.authority, false, auction.quoteTokenProgram),
+        allowlistProof: [],
+        maxBaseAllocation: thisOpenOrders.maxBaseAllocation,
       }
       users.push(thisUser);
     }
     return users
-  }
\ No newline at end of file
+  }
+
+// Inits the user's open orders account, the user signs for it
+export async function createOpenOrders(provider: anchor.Provider, auction: Auction, user: User): Promise<void> {
+    let tx = new anchor.web3.Transaction();
+    tx.add(genInstr.initOpenOrders({ ...user }, { ...user, ...auction }));
+    await provider.send(tx, [user.userKeypair], {skipPreflight: true});
+  }
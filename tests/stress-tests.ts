@@ -158,7 +158,7 @@ describe("stress-tests", () => {
             try {
                 let tx = new anchor.web3.Transaction;
                 tx.add(genInstr.calculateClearingPrice(
-                {limit: new BN(limit)},
+                {limit},
                 {...auction}
                 ));
                 await provider.send(tx, [], {skipPreflight: true});
@@ -184,7 +184,7 @@ describe("stress-tests", () => {
             try {
                 let tx = new anchor.web3.Transaction;
                 tx.add(genInstr.matchOrders(
-                {limit: new BN(limit)},
+                {limit},
                 {...auction}
                 ));
                 await provider.send(tx, [], {skipPreflight: true});